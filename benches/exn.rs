@@ -0,0 +1,66 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hint::black_box;
+
+use criterion::{BatchSize, BenchmarkId, Criterion, criterion_group, criterion_main};
+use exn::Exn;
+
+#[derive(Debug, thiserror::Error)]
+#[error("leaf")]
+struct Leaf;
+
+#[derive(Debug, thiserror::Error)]
+#[error("wrapper")]
+struct Wrapper(#[source] Leaf);
+
+#[derive(Debug, thiserror::Error)]
+#[error("link")]
+struct Link(#[source] Option<Box<Link>>);
+
+/// Returns a source chain of `len` errors.
+fn chain(len: usize) -> Link {
+    (1..len).fold(Link(None), |source, _| Link(Some(Box::new(source))))
+}
+
+fn new(c: &mut Criterion) {
+    let mut group = c.benchmark_group("new");
+    group.bench_function("leaf", |b| b.iter(|| Exn::new(black_box(Leaf))));
+    group.bench_function("with_source", |b| {
+        b.iter(|| Exn::new(black_box(Wrapper(Leaf))))
+    });
+    group.finish();
+}
+
+/// Compares creating an [`Exn`] from a deep source chain, which walks the chain eagerly, with also
+/// rendering it, which any deferred walk would have to do anyway.
+fn chain_walk(c: &mut Criterion) {
+    let mut group = c.benchmark_group("chain");
+    for len in [1, 8, 64] {
+        group.bench_with_input(BenchmarkId::new("new", len), &len, |b, &len| {
+            b.iter_batched(|| chain(len), Exn::new, BatchSize::SmallInput)
+        });
+        group.bench_with_input(BenchmarkId::new("new_and_debug", len), &len, |b, &len| {
+            b.iter_batched(
+                || chain(len),
+                |error| format!("{:?}", Exn::new(error)),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, new, chain_walk);
+criterion_main!(benches);
@@ -23,7 +23,7 @@
 
 use std::error::Error;
 
-use exn::{Exn, Frame, Result, ResultExt, bail};
+use exn::{Exn, Result, ResultExt, bail};
 
 use crate::http::HttpError;
 
@@ -50,19 +50,10 @@ fn main() -> Result<(), MainError> {
     }
 }
 
-/// Walk the error chain and extract HTTP status code if present.
+/// Search the error tree and extract HTTP status code if present.
 fn extract_http_status<E: Error + Send + Sync>(err: &Exn<E>) -> Option<u16> {
-    find_error::<HttpError>(err).map(|http_err| http_err.status)
-}
-
-fn find_error<T: Error + 'static>(exn: &Exn<impl Error + Send + Sync>) -> Option<&T> {
-    fn walk<T: Error + 'static>(frame: &Frame) -> Option<&T> {
-        if let Some(e) = frame.error().downcast_ref::<T>() {
-            return Some(e);
-        }
-        frame.children().iter().find_map(walk)
-    }
-    walk(exn.frame())
+    err.downcast_ref::<HttpError>()
+        .map(|http_err| http_err.status)
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -0,0 +1,66 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::boxed::Box;
+use core::{
+    error::Error,
+    fmt::{self, Debug, Display, Formatter},
+};
+
+/// [`Error`] that wraps a type-erased error
+///
+/// Since `Box<dyn Error>` does not itself implement [`Error`], this allows errors whose types
+/// cannot be named (e.g., those returned by plugins) to participate in the exception tree (see
+/// [`Exn::raise_boxed`]). All [`Error`] methods are delegated to the wrapped error.
+///
+/// [`Exn::raise_boxed`]: crate::Exn::raise_boxed
+pub struct BoxError(Box<dyn Error + Send + Sync + 'static>);
+
+impl BoxError {
+    /// Creates a new [`BoxError`] that wraps `error`.
+    #[must_use]
+    pub fn new(error: Box<dyn Error + Send + Sync + 'static>) -> Self {
+        Self(error)
+    }
+
+    /// Returns the wrapped error.
+    #[must_use]
+    pub fn into_inner(self) -> Box<dyn Error + Send + Sync + 'static> {
+        self.0
+    }
+}
+
+impl Debug for BoxError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Display for BoxError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Error for BoxError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.source()
+    }
+}
+
+impl From<Box<dyn Error + Send + Sync + 'static>> for BoxError {
+    fn from(error: Box<dyn Error + Send + Sync + 'static>) -> Self {
+        Self(error)
+    }
+}
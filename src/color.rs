@@ -0,0 +1,68 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt::{self, Display, Formatter};
+
+/// ANSI styles for [`Frame::debug_full_colored`]
+///
+/// Each field holds the [SGR] parameters (e.g., `"1"` for bold) applied to a part of the output,
+/// or [`None`] to leave that part unstyled. [`ColorSpec::NONE`] disables color entirely, which is
+/// appropriate for output that is not a terminal (see [`std::io::IsTerminal`]).
+///
+/// The [`Default`] styles error messages bold, locations dim, and tree connectors bright black.
+///
+/// [`Frame::debug_full_colored`]: crate::Frame::debug_full_colored
+/// [SGR]: https://en.wikipedia.org/wiki/ANSI_escape_code#SGR
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ColorSpec {
+    /// Style for error messages
+    pub message: Option<&'static str>,
+
+    /// Style for locations
+    pub location: Option<&'static str>,
+
+    /// Style for tree connectors and indentation
+    pub connector: Option<&'static str>,
+}
+
+impl ColorSpec {
+    /// [`ColorSpec`] that emits no ANSI escape codes
+    pub const NONE: Self = Self {
+        message: None,
+        location: None,
+        connector: None,
+    };
+}
+
+impl Default for ColorSpec {
+    fn default() -> Self {
+        Self {
+            message: Some("1"),
+            location: Some("2"),
+            connector: Some("90"),
+        }
+    }
+}
+
+/// Wrapper that renders its content with an optional SGR style
+pub(crate) struct Paint<T>(pub Option<&'static str>, pub T);
+
+impl<T: Display> Display for Paint<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0 {
+            Some(sgr) => write!(f, "\x1b[{sgr}m{}\x1b[0m", self.1),
+            None => Display::fmt(&self.1, f),
+        }
+    }
+}
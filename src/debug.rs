@@ -0,0 +1,339 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt::{self, Display, Formatter, Write};
+
+use crate::{ColorSpec, exn::SourceLocation};
+
+/// Function that formats a location for [`Frame::debug_with`]
+///
+/// The arguments are the file path (after applying [`DebugOptions::strip_prefix`] and
+/// [`DebugOptions::relative`]), the line, and the column.
+///
+/// [`Frame::debug_with`]: crate::Frame::debug_with
+pub type LocationFormat = fn(f: &mut Formatter, file: &str, line: u32, column: u32) -> fmt::Result;
+
+/// Options for [`Frame::debug_with`]
+///
+/// The [`Default`] options produce the same output as [`Frame::debug_full`].
+///
+/// [`Frame::debug_with`]: crate::Frame::debug_with
+/// [`Frame::debug_full`]: crate::Frame::debug_full
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    all(feature = "threads", feature = "timestamps"),
+    expect(clippy::struct_excessive_bools, reason = "the options are independent")
+)]
+pub struct DebugOptions<'a> {
+    /// ANSI styles (see [`ColorSpec`])
+    pub colors: ColorSpec,
+
+    /// Prefix to strip from the file path of each location
+    ///
+    /// The prefix only matches whole path components, so `tests` strips `tests/a.rs` but not
+    /// `testsuite/a.rs`. Any path separators that follow the prefix are also stripped.
+    pub strip_prefix: Option<&'a str>,
+
+    /// Whether to make absolute file paths relative to the current working directory
+    ///
+    /// This is applied after [`strip_prefix`](Self::strip_prefix) if the latter does not match.
+    /// It has no effect without the `std` feature or if the current working directory cannot be
+    /// determined.
+    pub relative: bool,
+
+    /// Glyphs that connect each [`Frame`] to its children (see [`Connectors`])
+    ///
+    /// [`Frame`]: crate::Frame
+    pub connectors: Connectors<'a>,
+
+    /// Whether to print the location of each [`Frame`]
+    ///
+    /// [`Frame`]: crate::Frame
+    pub locations: bool,
+
+    /// Custom formatting for locations
+    ///
+    /// If [`None`], locations are formatted as `file:line:column`.
+    pub location_format: Option<LocationFormat>,
+
+    /// Maximum number of characters to print from the message of each [`Frame`]
+    ///
+    /// Longer messages are cut off after this many characters and followed by an ellipsis (`…`).
+    /// Only the output is affected; the underlying errors are unchanged.
+    ///
+    /// [`Frame`]: crate::Frame
+    pub max_message_len: Option<usize>,
+
+    /// Maximum number of children to print for each [`Frame`]
+    ///
+    /// If a [`Frame`] has more children, only the first ones are printed, followed by a line of
+    /// the form `... and N more`, and the help text of the hidden children is omitted. Only the
+    /// output is affected; the underlying children are unchanged.
+    ///
+    /// [`Frame`]: crate::Frame
+    pub max_children: Option<usize>,
+
+    /// Whether to print the time at which each [`Frame`] was created
+    ///
+    /// Timestamps are printed as seconds since the Unix epoch with microsecond precision.
+    ///
+    /// [`Frame`]: crate::Frame
+    #[cfg(feature = "timestamps")]
+    pub timestamps: bool,
+
+    /// Whether to print the thread on which each [`Frame`] was created
+    ///
+    /// Threads are printed by name, or by ID if they are unnamed.
+    ///
+    /// [`Frame`]: crate::Frame
+    #[cfg(feature = "threads")]
+    pub threads: bool,
+}
+
+impl Default for DebugOptions<'_> {
+    fn default() -> Self {
+        Self {
+            colors: ColorSpec::NONE,
+            strip_prefix: None,
+            relative: false,
+            connectors: Connectors::UNICODE,
+            locations: true,
+            location_format: None,
+            max_message_len: None,
+            max_children: None,
+            #[cfg(feature = "timestamps")]
+            timestamps: false,
+            #[cfg(feature = "threads")]
+            threads: false,
+        }
+    }
+}
+
+impl DebugOptions<'_> {
+    /// Returns options that print only the file path of each location.
+    ///
+    /// Omitting lines and columns keeps the output stable when unrelated code shifts, which is
+    /// useful for snapshot tests. Locations cannot be rewritten in the exception tree itself since
+    /// [`Location`](core::panic::Location)s can only be created by the compiler, so normalization
+    /// is applied when formatting instead.
+    #[must_use]
+    pub fn normalized() -> Self {
+        Self {
+            location_format: Some(|f, file, _, _| f.write_str(file)),
+            ..Self::default()
+        }
+    }
+}
+
+/// Glyphs that connect [`Frame`]s to their children in [`Frame::debug_with`]
+///
+/// Each glyph is printed after the indentation of the parent [`Frame`], so the [`vertical`] and
+/// [`space`] glyphs also determine the width of each level of indentation. All four glyphs should
+/// have the same width.
+///
+/// [`Frame`]: crate::Frame
+/// [`Frame::debug_with`]: crate::Frame::debug_with
+/// [`vertical`]: Self::vertical
+/// [`space`]: Self::space
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Connectors<'a> {
+    /// Glyph that precedes each child except the last
+    pub branch: &'a str,
+
+    /// Glyph that precedes the last child
+    pub last: &'a str,
+
+    /// Indentation beneath a child that has subsequent siblings
+    pub vertical: &'a str,
+
+    /// Indentation beneath the last child
+    pub space: &'a str,
+}
+
+impl Connectors<'static> {
+    /// Box-drawing glyphs (used by [`Frame::debug_full`])
+    ///
+    /// [`Frame::debug_full`]: crate::Frame::debug_full
+    pub const UNICODE: Self = Self {
+        branch: "├─ ",
+        last: "└─ ",
+        vertical: "│  ",
+        space: "   ",
+    };
+
+    /// ASCII-only glyphs
+    pub const ASCII: Self = Self {
+        branch: "|- ",
+        last: "`- ",
+        vertical: "|  ",
+        space: "   ",
+    };
+}
+
+/// Resolved [`DebugOptions`] for formatting [`Frame`]s recursively
+///
+/// [`Frame`]: crate::Frame
+pub(crate) struct Style<'a> {
+    pub options: DebugOptions<'a>,
+    cwd: Option<&'a str>,
+}
+
+impl<'a> Style<'a> {
+    pub fn new(colors: ColorSpec) -> Self {
+        Self {
+            options: DebugOptions {
+                colors,
+                ..DebugOptions::default()
+            },
+            cwd: None,
+        }
+    }
+
+    pub const fn with_options(options: &DebugOptions<'a>, cwd: Option<&'a str>) -> Self {
+        Self {
+            options: *options,
+            cwd,
+        }
+    }
+
+    /// Formats `location` according to the options, or returns [`None`] if locations are hidden.
+    pub fn location<'l>(&self, location: SourceLocation<'l>) -> Option<StyledLocation<'l>> {
+        self.options.locations.then(|| StyledLocation {
+            format: self.options.location_format,
+            file: self.file(location.file),
+            line: location.line,
+            column: location.column,
+        })
+    }
+
+    /// Wraps `message` so that it is truncated according to the options.
+    pub const fn message<T>(&self, message: T) -> Truncated<T> {
+        Truncated {
+            message,
+            max_len: self.options.max_message_len,
+        }
+    }
+
+    /// Strips the first matching prefix (and any subsequent path separators) from `file`.
+    ///
+    /// A prefix only matches whole path components of `file`.
+    fn file<'f>(&self, file: &'f str) -> &'f str {
+        const SEPARATORS: [char; 2] = ['/', '\\'];
+
+        [self.options.strip_prefix, self.cwd]
+            .into_iter()
+            .flatten()
+            .find_map(|prefix| {
+                let rest = file.strip_prefix(prefix)?;
+                (rest.is_empty() || rest.starts_with(SEPARATORS) || prefix.ends_with(SEPARATORS))
+                    .then_some(rest)
+            })
+            .map_or(file, |file| file.trim_start_matches(SEPARATORS))
+    }
+}
+
+/// Location formatted according to [`DebugOptions`]
+pub(crate) struct StyledLocation<'a> {
+    format: Option<LocationFormat>,
+    file: &'a str,
+    line: u32,
+    column: u32,
+}
+
+impl fmt::Display for StyledLocation<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.format {
+            Some(format) => format(f, self.file, self.line, self.column),
+            None => write!(f, "{}:{}:{}", self.file, self.line, self.column),
+        }
+    }
+}
+
+/// Message truncated according to [`DebugOptions::max_message_len`]
+pub(crate) struct Truncated<T> {
+    message: T,
+    max_len: Option<usize>,
+}
+
+impl<T: Display> Display for Truncated<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let Some(max_len) = self.max_len else {
+            return Display::fmt(&self.message, f);
+        };
+        let mut writer = Truncator::new(&mut *f, max_len);
+        let result = write!(writer, "{}", self.message);
+        if writer.truncated {
+            f.write_char('…')
+        } else {
+            result
+        }
+    }
+}
+
+/// [`Write`] adapter that stops after a number of characters
+///
+/// Once a write would exceed the limit, the characters that fit are written,
+/// [`Truncator::truncated`] is set, and [`fmt::Error`] is returned so that formatting stops early.
+pub(crate) struct Truncator<W> {
+    inner: W,
+    remaining: usize,
+    single_line: bool,
+    pub(crate) truncated: bool,
+}
+
+impl<W: Write> Truncator<W> {
+    pub const fn new(inner: W, max_len: usize) -> Self {
+        Self {
+            inner,
+            remaining: max_len,
+            single_line: false,
+            truncated: false,
+        }
+    }
+
+    /// Replaces line breaks (`\n` or `\r`) with spaces.
+    pub const fn single_line(mut self) -> Self {
+        self.single_line = true;
+        self
+    }
+
+    fn write_untruncated(&mut self, s: &str) -> fmt::Result {
+        if !self.single_line {
+            return self.inner.write_str(s);
+        }
+        for (i, line) in s.split(['\n', '\r']).enumerate() {
+            if i > 0 {
+                self.inner.write_char(' ')?;
+            }
+            self.inner.write_str(line)?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for Truncator<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.truncated {
+            return Err(fmt::Error);
+        }
+        if let Some((end, _)) = s.char_indices().nth(self.remaining) {
+            self.truncated = true;
+            self.write_untruncated(&s[..end])?;
+            Err(fmt::Error)
+        } else {
+            self.remaining -= s.chars().count();
+            self.write_untruncated(s)
+        }
+    }
+}
@@ -14,7 +14,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::error::Error;
+use core::{error::Error, panic::Location};
 
 use crate::Exn;
 
@@ -28,6 +28,40 @@ pub trait ErrorExt: Error + Send + Sync + 'static {
     {
         Exn::new(self)
     }
+
+    /// Raises this [`Error`] as a new [`Exn`] at an explicit `location`.
+    ///
+    /// See [`Exn::new_at`].
+    fn raise_at(self, location: &'static Location<'static>) -> Exn<Self>
+    where
+        Self: Sized,
+    {
+        Exn::new_at(self, location)
+    }
+
+    /// Raises this [`Error`] as a new [`Exn`] with the given `children`.
+    ///
+    /// This is the fluent form of [`Exn::raise_all`].
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use exn::ErrorExt;
+    ///
+    /// let children = ["a", "b"].map(|name| io::Error::other(name).raise());
+    /// let exn = io::Error::other("parent").raise_with_children(children);
+    /// assert_eq!(exn.frame().children().len(), 2);
+    /// ```
+    #[track_caller]
+    fn raise_with_children<T, I>(self, children: I) -> Exn<Self>
+    where
+        Self: Sized,
+        T: Error + Send + Sync + 'static,
+        I: IntoIterator,
+        I::Item: Into<Exn<T>>,
+    {
+        Exn::raise_all(children, self)
+    }
 }
 
 impl<T: Error + Send + Sync + 'static> ErrorExt for T {}
@@ -14,182 +14,1826 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::{
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{
     error::Error,
-    fmt::{self, Debug, Display, Formatter},
+    fmt::{self, Debug, Display, Formatter, Write as _},
+    hash::{Hash, Hasher},
     marker::PhantomData,
-    ops::Deref,
+    ops::{Deref, Range},
     panic::Location,
 };
+#[cfg(feature = "backtrace")]
+use std::backtrace::{Backtrace, BacktraceStatus};
+#[cfg(any(feature = "backtrace", feature = "threads"))]
+use std::sync::Arc;
+#[cfg(feature = "threads")]
+use std::thread::{self, ThreadId};
+#[cfg(feature = "timestamps")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{
+    BoxError, ColorSpec, DebugOptions, FrameVisitor, Level, Span,
+    color::Paint,
+    debug::{Style, Truncator},
+};
 
 /// Exception type that can hold an error tree and additional context
+///
+/// Like the errors it holds, every [`Exn`] is [`Send`], [`Sync`], and `'static`, so it can be sent
+/// across threads (e.g., returned from a spawned task).
 pub struct Exn<E: Error + Send + Sync + 'static> {
     frame: Box<Frame>,
+    #[cfg(feature = "backtrace")]
+    backtrace: Option<Arc<Backtrace>>,
     phantom: PhantomData<E>,
 }
 
+/// Default maximum length of [`Exn::log_line`] in characters
+pub const LOG_LINE_MAX_LEN: usize = 200;
+
 impl<E: Error + Send + Sync + 'static> Exn<E> {
     /// Creates a new [`Exn`] with the given `error`.
     ///
     /// The types of all [source `Error`s] are erased, but their [`String`] representations are
     /// retained.
     ///
+    /// The source chain is walked eagerly, allocating one [`Frame`] and one [`String`] per source.
+    ///
     /// See [`ErrorExt::raise`] for a fluent way to convert an error into an [`Exn`].
     ///
     /// [source `Error`s]: Error::source
     /// [`ErrorExt::raise`]: crate::ErrorExt::raise
     #[track_caller]
     pub fn new(error: E) -> Self {
-        struct SourceError(String);
+        Self::new_at(error, Location::caller())
+    }
+
+    /// Creates a new [`Exn`] with the given `error`, retaining the types of sources of type `S`.
+    ///
+    /// This is like [`Exn::new`], except that each [source `Error`] of type `S` is cloned into its
+    /// [`Frame`] rather than being converted to a [`String`], so it remains accessible via
+    /// [`Frame::downcast_ref`] (e.g., to find a wrapped error by type with [`Exn::find`]). The
+    /// types of all other sources are erased as usual.
+    ///
+    /// ```
+    /// use std::num::ParseIntError;
+    ///
+    /// use exn::Exn;
+    ///
+    /// #[derive(Debug, thiserror::Error)]
+    /// #[error("invalid config")]
+    /// struct ConfigError(#[from] ParseIntError);
+    ///
+    /// let source = "x".parse::<i32>().unwrap_err();
+    /// let exn = Exn::new_chaining::<ParseIntError>(ConfigError::from(source.clone()));
+    /// let child = exn.find::<ParseIntError>().unwrap();
+    /// assert_eq!(child.downcast_ref::<ParseIntError>(), Some(&source));
+    /// ```
+    ///
+    /// [source `Error`]: Error::source
+    #[track_caller]
+    pub fn new_chaining<S: Error + Clone + Send + Sync + 'static>(error: E) -> Self {
+        fn convert<S: Error + Clone + Send + Sync + 'static>(
+            source: &(dyn Error + 'static),
+        ) -> Box<dyn Error + Send + Sync + 'static> {
+            match source.downcast_ref::<S>() {
+                Some(source) => Box::new(source.clone()),
+                None => Box::new(SourceError(source.to_string())),
+            }
+        }
+
+        Self {
+            frame: Box::new(
+                Frame::new_at_with(error, Location::caller(), convert::<S>).with_tracing_span(),
+            ),
+            #[cfg(feature = "backtrace")]
+            backtrace: capture_backtrace(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Creates a new [`Exn`] with the given `error` at an explicit `location`.
+    ///
+    /// This is useful for helpers that forward their caller's location (via
+    /// [`Location::caller`]) rather than reporting their own. Otherwise, this is equivalent to
+    /// [`Exn::new`].
+    ///
+    /// See [`ErrorExt::raise_at`] for a fluent alternative.
+    ///
+    /// [`ErrorExt::raise_at`]: crate::ErrorExt::raise_at
+    pub fn new_at(error: E, location: &'static Location<'static>) -> Self {
+        Self {
+            frame: Box::new(Frame::new_at(error, location).with_tracing_span()),
+            #[cfg(feature = "backtrace")]
+            backtrace: capture_backtrace(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Recovers an [`Exn`] from a type-erased [`Frame`] (e.g., from [`Frame::from`]).
+    ///
+    /// This succeeds if and only if the error of `frame` is of type `E`. The [`Frame`] is
+    /// otherwise unchanged. Since [`Frame`]s do not retain backtraces, the resulting [`Exn`] has
+    /// none.
+    ///
+    /// # Errors
+    ///
+    /// If the error of `frame` is not of type `E`, `frame` is returned unchanged.
+    pub fn try_from_frame(frame: Frame) -> core::result::Result<Self, Frame> {
+        if frame.error.is::<E>() {
+            Ok(Self {
+                frame: Box::new(frame),
+                #[cfg(feature = "backtrace")]
+                backtrace: None,
+                phantom: PhantomData,
+            })
+        } else {
+            Err(frame)
+        }
+    }
+
+    /// Creates a new [`Exn`] with the given `error` and its `children`.
+    #[track_caller]
+    pub fn raise_all<T, I>(children: I, error: E) -> Self
+    where
+        T: Error + Send + Sync + 'static,
+        I: IntoIterator,
+        I::Item: Into<Exn<T>>,
+    {
+        let mut new_exn = Exn::new(error);
+        // Note: We can't use `Vec::extend` since `#[track_caller]` on closures is currently
+        // unstable.
+        for child in children {
+            new_exn.frame.children.push(*child.into().frame);
+        }
+        new_exn
+    }
+
+    /// Creates a new [`Exn`] with the given `error` and its type-erased `children`.
+    ///
+    /// Unlike [`Exn::raise_all`], the children may be [`Exn`]s of different types (or [`Frame`]s),
+    /// since each is converted into a [`Frame`]. The errors of the children remain accessible via
+    /// [`Frame::downcast_ref`], but their types are no longer known statically.
+    ///
+    /// ```
+    /// use std::{fmt, io};
+    ///
+    /// use exn::{ErrorExt, Exn, Frame};
+    ///
+    /// let children = [
+    ///     Frame::from(io::Error::other("io").raise()),
+    ///     Frame::from(fmt::Error.raise()),
+    /// ];
+    /// let exn = Exn::raise_all_dyn(children, io::Error::other("parent"));
+    /// assert_eq!(exn.frame().children().len(), 2);
+    /// ```
+    #[track_caller]
+    pub fn raise_all_dyn<I>(children: I, error: E) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Frame>,
+    {
+        Exn::new(error).with_siblings(children)
+    }
+
+    /// Appends the top-level [`Frame`]s of `siblings` to the children of this [`Exn`]'s
+    /// top-level [`Frame`].
+    ///
+    /// Unlike [`Exn::raise_all`], this does not create a new [`Frame`], so the type and location of
+    /// `self` are retained. The appended [`Frame`]s follow any existing children.
+    ///
+    /// `siblings` may be [`Exn`]s of any type or [`Frame`]s (e.g., from [`Exn::split`]).
+    #[must_use]
+    pub fn with_siblings<I>(mut self, siblings: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Frame>,
+    {
+        self.frame
+            .children
+            .extend(siblings.into_iter().map(Into::into));
+        self
+    }
+
+    /// Appends the top-level [`Frame`] of `child` to the children of this [`Exn`]'s top-level
+    /// [`Frame`] in place.
+    ///
+    /// This is useful for building an exception tree incrementally:
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use exn::ErrorExt;
+    ///
+    /// let mut exn = io::Error::other("invalid fields").raise();
+    /// for field in ["name", "email"] {
+    ///     exn.push_child(io::Error::other(format!("missing {field}")).raise());
+    /// }
+    /// assert_eq!(exn.frame().children().len(), 2);
+    /// ```
+    ///
+    /// Like [`Exn::with_siblings`], `child` may be an [`Exn`] of any type or a [`Frame`].
+    pub fn push_child(&mut self, child: impl Into<Frame>) {
+        self.frame.children.push(child.into());
+    }
+
+    /// Removes and returns the child of the top-level [`Frame`] at `index`, if any.
+    ///
+    /// Subsequent children are shifted down by one. The type of the returned [`Frame`]'s error may
+    /// be recovered via [`Exn::try_from_frame`].
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use exn::{ErrorExt, Exn};
+    ///
+    /// let children = ["a", "b"].map(|name| io::Error::other(name).raise());
+    /// let mut exn = Exn::raise_all(children, io::Error::other("parent"));
+    /// let child = exn.take_child(0).unwrap();
+    /// assert_eq!(child.to_string(), "a");
+    /// assert_eq!(exn.child_count(), 1);
+    /// assert!(exn.take_child(1).is_none());
+    /// ```
+    pub fn take_child(&mut self, index: usize) -> Option<Frame> {
+        (index < self.frame.children.len()).then(|| self.frame.children.remove(index))
+    }
+
+    /// Replaces the children of the top-level [`Frame`] with `children`.
+    ///
+    /// The previous children are dropped. Together with [`Frame::consume`] or [`Exn::split`], this
+    /// allows for arbitrary transformations of the children of an [`Exn`].
+    ///
+    /// ```
+    /// use std::{io, iter};
+    ///
+    /// use exn::{ErrorExt, Exn};
+    ///
+    /// let children = ["a", "b"].map(|name| io::Error::other(name).raise());
+    /// let mut exn = Exn::raise_all(children, io::Error::other("parent"));
+    /// let mut children: Vec<_> = iter::from_fn(|| exn.take_child(0)).collect();
+    /// children.reverse();
+    /// exn.set_children(children);
+    /// assert_eq!(exn.frame().children()[0].to_string(), "b");
+    /// ```
+    pub fn set_children(&mut self, children: Vec<Frame>) {
+        self.frame.children = children;
+    }
+
+    /// Appends a [`Frame`] for `source` to the children of this [`Exn`]'s top-level [`Frame`].
+    ///
+    /// The [`Error::source`] chain of `source` is walked just like in [`Exn::new`], and the new
+    /// [`Frame`]s share the callsite of this method as their location. The appended [`Frame`]
+    /// follows any existing children.
+    #[must_use]
+    #[track_caller]
+    pub fn with_source<S: Error + Send + Sync + 'static>(mut self, source: S) -> Self {
+        self.frame
+            .children
+            .push(Frame::new_at(source, Location::caller()));
+        self
+    }
+
+    /// Truncates the exception tree so that no [`Frame`] is deeper than `max_depth`.
+    ///
+    /// The top-level [`Frame`] has a depth of `0`. The descendants of each [`Frame`] at
+    /// `max_depth` are replaced by a single type-erased marker [`Frame`] whose message is
+    /// `... (N more frames)`, where `N` is the number of pruned [`Frame`]s. The marker shares the
+    /// location of its parent. All retained [`Frame`]s are otherwise unchanged.
+    #[must_use]
+    pub fn prune_depth(mut self, max_depth: usize) -> Self {
+        self.frame.prune_depth(max_depth);
+        self
+    }
+
+    /// Collapses identical children of the top-level [`Frame`] into a single child each.
+    ///
+    /// Children are compared via [`Frame`]'s [`PartialEq`] implementation (ignoring their own
+    /// multiplicities), so only children with the same message, location, attachments, and
+    /// descendants are collapsed, regardless of whether they are consecutive. Children are grouped
+    /// by their hashes first, so this takes linear time in the number of children. The first of
+    /// each group of identical children is retained in its original position, and its
+    /// [multiplicity] is the sum of the group's multiplicities. Multiplicities greater than `1`
+    /// are rendered as `(xN)` in [`Exn`]'s [`Debug`] representation.
+    ///
+    /// Only the immediate children of the top-level [`Frame`] are deduplicated; deeper descendants
+    /// are unchanged.
+    ///
+    /// [multiplicity]: Frame::multiplicity
+    #[must_use]
+    pub fn dedup_children(mut self) -> Self {
+        self.frame.dedup_children();
+        self
+    }
+
+    /// Retains only the children of the top-level [`Frame`] for which `f` returns `true`.
+    ///
+    /// Removed children are dropped along with their descendants. The order of the retained
+    /// children is unchanged, so [`Error::source`] of the top-level [`Frame`] follows the first
+    /// retained child.
+    ///
+    /// Only the immediate children of the top-level [`Frame`] are visited; see
+    /// [`Exn::retain_all`] to filter all descendants.
+    pub fn retain_children<F: FnMut(&Frame) -> bool>(&mut self, f: F) {
+        self.frame.children.retain(f);
+    }
+
+    /// Retains only the descendants of the top-level [`Frame`] for which `f` returns `true`.
+    ///
+    /// This is the recursive equivalent of [`Exn::retain_children`]. [`Frame`]s are visited top
+    /// down, so the descendants of a removed [`Frame`] are dropped without being visited. The
+    /// top-level [`Frame`] itself is always retained.
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use exn::ErrorExt;
+    ///
+    /// let child = io::Error::other("secret").raise();
+    /// let mut exn = child.raise(io::Error::other("public"));
+    /// exn.retain_all(|frame| frame.to_string() != "secret");
+    /// assert!(exn.frame().children().is_empty());
+    /// ```
+    pub fn retain_all<F: FnMut(&Frame) -> bool>(&mut self, mut f: F) {
+        self.frame.retain_all(&mut f);
+    }
+
+    /// Calls `f` with mutable access to each descendant of the top-level [`Frame`] in preorder.
+    ///
+    /// See [`Frame::walk_mut`] for details. The top-level [`Frame`] itself is not visited, since
+    /// its error must remain of type `E`.
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use exn::ErrorExt;
+    ///
+    /// let child = io::Error::other("password=hunter2").raise();
+    /// let mut exn = child.raise(io::Error::other("login failed"));
+    /// exn.walk_mut(|frame| {
+    ///     if frame.to_string().contains("password") {
+    ///         frame.replace_error(io::Error::other("<redacted>"));
+    ///     }
+    /// });
+    /// assert_eq!(exn.frame().children()[0].to_string(), "<redacted>");
+    /// ```
+    pub fn walk_mut<F: FnMut(&mut Frame)>(&mut self, mut f: F) {
+        for child in &mut self.frame.children {
+            child.walk_mut(&mut f);
+        }
+    }
+
+    /// Visits all [`Frame`]s in this [`Exn`] with `visitor`.
+    ///
+    /// See [`Frame::accept`].
+    pub fn visit<V: FrameVisitor + ?Sized>(&self, visitor: &mut V) {
+        self.frame.accept(visitor);
+    }
+
+    /// Stably sorts the children of the top-level [`Frame`] by their locations.
+    ///
+    /// Locations are ordered by file, then line, then column, so children at the same location
+    /// retain their relative order. This is useful for producing deterministic output when children
+    /// are collected in a nondeterministic order (e.g., from a parallel iterator).
+    ///
+    /// Only the immediate children of the top-level [`Frame`] are sorted; see
+    /// [`Exn::sort_tree_by_location`] to sort all descendants.
+    pub fn sort_children_by_location(&mut self) {
+        self.frame.sort_children_by_location(false);
+    }
+
+    /// Stably sorts the children of every [`Frame`] in the exception tree by their locations.
+    ///
+    /// This is the recursive equivalent of [`Exn::sort_children_by_location`].
+    pub fn sort_tree_by_location(&mut self) {
+        self.frame.sort_children_by_location(true);
+    }
+
+    /// Creates a new [`Exn`] where `self` is its child.
+    #[track_caller]
+    pub fn raise<T: Error + Send + Sync + 'static>(mut self, error: T) -> Exn<T> {
+        // Reuse the allocation of the top-level `Frame` for the new one.
+        let child = core::mem::replace(&mut *self.frame, Frame::new(error));
+        // Most frames have at most one child, so avoid `Vec`'s default minimum capacity of four.
+        // Storing the first child inline (e.g., with a small-vector) is not an option because
+        // `Frame` would then be infinitely sized.
+        self.frame.children.reserve_exact(1);
+        self.frame.children.push(child);
+        Exn {
+            frame: self.frame,
+            #[cfg(feature = "backtrace")]
+            backtrace: self.backtrace,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Creates a new [`Exn`] where `self` is its child and its error is the type-erased `error`.
+    ///
+    /// This is like [`Exn::raise`] for errors whose types cannot be named. The [`BoxError`] of the
+    /// new [`Exn`] delegates to `error`, so it is rendered like any other error.
+    ///
+    /// ```
+    /// use std::{error::Error, io};
+    ///
+    /// use exn::ErrorExt;
+    ///
+    /// let context: Box<dyn Error + Send + Sync> = Box::new(io::Error::other("plugin failed"));
+    /// let exn = io::Error::other("child").raise().raise_boxed(context);
+    /// assert_eq!(exn.to_string(), "plugin failed");
+    /// assert_eq!(exn.frame().children()[0].to_string(), "child");
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn raise_boxed(self, error: Box<dyn Error + Send + Sync + 'static>) -> Exn<BoxError> {
+        self.raise(BoxError::new(error))
+    }
+
+    /// Creates a new [`Exn`] whose error is converted from the top-level error of `self` via
+    /// [`From`], where `self` is its child.
+    ///
+    /// This is like [`Exn::raise`], except that the new error is constructed from the original.
+    /// Since the original error is moved into the new one, the child [`Frame`] retains only its
+    /// [`String`] representation; its location, attachments, and children are unchanged. The
+    /// [sources] of the new error are not added as [`Frame`]s (unlike [`Exn::new`]), since they
+    /// usually duplicate the child.
+    ///
+    /// In contrast, [`Exn::map_err`] converts the top-level error in place, and
+    /// `Frame::from(exn)` only erases the type of an [`Exn`]; neither creates a new [`Frame`].
+    ///
+    /// [sources]: Error::source
+    #[must_use]
+    #[track_caller]
+    pub fn raise_into<T>(self) -> Exn<T>
+    where
+        T: Error + From<E> + Send + Sync + 'static,
+    {
+        let mut child = *self.frame;
+        let erased = Box::new(SourceError(child.error.to_string()));
+        let error: E = downcast_owned(core::mem::replace(&mut child.error, erased));
+        Exn {
+            frame: Box::new(Frame::from_parts(
+                Box::new(T::from(error)),
+                Location::caller(),
+                vec![child],
+            )),
+            #[cfg(feature = "backtrace")]
+            backtrace: self.backtrace,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Converts the top-level error of this [`Exn`] into `T` via [`From`].
+    ///
+    /// Unlike [`Exn::raise`], this does not create a new [`Frame`]; the location, children, and
+    /// attachments of the top-level [`Frame`] are retained.
+    #[must_use]
+    pub fn map_err<T>(self) -> Exn<T>
+    where
+        T: Error + From<E> + Send + Sync + 'static,
+    {
+        self.map_into()
+    }
+
+    /// Converts the top-level error via [`Into`] without creating a new [`Frame`].
+    pub(crate) fn map_into<T>(self) -> Exn<T>
+    where
+        E: Into<T>,
+        T: Error + Send + Sync + 'static,
+    {
+        Exn {
+            frame: Box::new(self.frame.map_error(E::into)),
+            #[cfg(feature = "backtrace")]
+            backtrace: self.backtrace,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Appends `: {extra}` to the message of the top-level [`Frame`] of this [`Exn`].
+    ///
+    /// This enriches the most recent context without adding a [`Frame`], which keeps the exception
+    /// tree shallow for incidental details. The error itself is unchanged, so the amendment is
+    /// only visible in the [`Display`] representations of the [`Frame`] and the [`Exn`] (and in
+    /// the representations derived from them).
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use exn::ErrorExt;
+    ///
+    /// let exn = io::Error::other("failed to read config")
+    ///     .raise()
+    ///     .amend("file not found");
+    /// assert_eq!(exn.to_string(), "failed to read config: file not found");
+    /// assert_eq!(exn.frame().error().to_string(), "failed to read config");
+    /// ```
+    #[must_use]
+    pub fn amend<C: Display>(mut self, extra: C) -> Self {
+        let extras = self.frame.extras_mut();
+        extras.amendments = Some(match extras.amendments.take() {
+            Some(amendments) => format!("{amendments}: {extra}"),
+            None => extra.to_string(),
+        });
+        self
+    }
+
+    /// Attaches a key-value pair to the top-level [`Frame`] of this [`Exn`].
+    ///
+    /// Attachments provide structured context without requiring a new error type. They are
+    /// accessible via [`Frame::attachments`] and are rendered beneath their [`Frame`] in [`Exn`]'s
+    /// [`Debug`] representation.
+    #[must_use]
+    pub fn attach<K: Into<Cow<'static, str>>, V: Display>(mut self, key: K, value: V) -> Self {
+        (self.frame.extras_mut().attachments).push((key.into(), value.to_string()));
+        self
+    }
+
+    /// Sets the [`Level`] of the top-level [`Frame`] of this [`Exn`].
+    ///
+    /// The [`Level`] is accessible via [`Frame::level`] and prefixes the [`Frame`] in [`Exn`]'s
+    /// [`Debug`] representation.
+    #[must_use]
+    pub fn with_level(mut self, level: Level) -> Self {
+        self.frame.extras_mut().level = Some(level);
+        self
+    }
+
+    /// Sets the error code of the top-level [`Frame`] of this [`Exn`].
+    ///
+    /// Error codes are application-defined (e.g., process exit codes for a CLI). Since the types of
+    /// child errors are erased, codes are stored alongside [`Frame`]s rather than derived from
+    /// errors. See [`Exn::code`].
+    #[must_use]
+    pub fn with_code(mut self, code: i32) -> Self {
+        self.frame.extras_mut().code = Some(code);
+        self
+    }
+
+    /// Sets the [`Span`] of the top-level [`Frame`] of this [`Exn`] to the byte `range` of `source`
+    /// with the given `label`.
+    ///
+    /// [`Span`]s are not rendered by [`Frame::debug_full`], but reporters can use them to point at
+    /// the offending input (e.g., `repr::Miette` exposes them as labels).
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use exn::ErrorExt;
+    ///
+    /// let exn = io::Error::other("unexpected token").raise().with_span(
+    ///     "let x = ;",
+    ///     8..9,
+    ///     "expected an expression",
+    /// );
+    /// let span = exn.frame().span().unwrap();
+    /// assert_eq!(&span.source[span.range.clone()], ";");
+    /// ```
+    #[must_use]
+    pub fn with_span(
+        mut self,
+        source: impl Into<String>,
+        range: Range<usize>,
+        label: impl Into<String>,
+    ) -> Self {
+        self.frame.extras_mut().span = Some(Span {
+            source: source.into(),
+            range,
+            label: label.into(),
+        });
+        self
+    }
+
+    /// Sets the help text of the top-level [`Frame`] of this [`Exn`].
+    ///
+    /// Help text is a user-facing suggestion for resolving the error, such as a command-line flag
+    /// to try. Unlike attachments, each [`Frame`] has at most one help text, and
+    /// [`Frame::debug_full`] renders it beneath the entire exception tree as `help: …`.
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use exn::ErrorExt;
+    ///
+    /// let exn = io::Error::other("file exists")
+    ///     .raise()
+    ///     .with_help("try running with --force");
+    /// assert!(format!("{exn:?}").ends_with("\nhelp: try running with --force"));
+    /// ```
+    #[must_use]
+    pub fn with_help<S: Display>(mut self, help: S) -> Self {
+        self.frame.extras_mut().help = Some(help.to_string());
+        self
+    }
+
+    /// Returns the error code of this [`Exn`], if any.
+    ///
+    /// This is the code of the first [`Frame`] along the primary chain (see [`Exn::chain`]) that
+    /// has one, so a code set on a low-level error survives being raised with additional context
+    /// unless the context sets its own code.
+    #[must_use]
+    pub fn code(&self) -> Option<i32> {
+        self.frame.spine().find_map(Frame::code)
+    }
+
+    /// Returns a reference to the underlying exception frame.
+    #[must_use]
+    pub fn frame(&self) -> &Frame {
+        &self.frame
+    }
+
+    /// Returns `true` if the top-level [`Frame`] of this [`Exn`] has any children.
+    ///
+    /// This is useful with [`Exn::push_child`] for only returning an error if any children were
+    /// accumulated.
+    #[must_use]
+    pub fn has_children(&self) -> bool {
+        !self.frame.children.is_empty()
+    }
+
+    /// Returns the number of children of the top-level [`Frame`] of this [`Exn`].
+    ///
+    /// See [`Frame::count`] for the number of all [`Frame`]s in the exception tree.
+    #[must_use]
+    pub fn child_count(&self) -> usize {
+        self.frame.children.len()
+    }
+
+    /// Returns a one-line summary of this [`Exn`].
+    ///
+    /// The summary is the [`Display`] representation of this [`Exn`] followed by the number of
+    /// descendant [`Frame`]s, if any (e.g., `fatal error (+3 causes)`). This is useful for labels
+    /// (e.g., metrics tags) where the entire exception tree would be too verbose.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        match self.frame.count() - 1 {
+            0 => self.to_string(),
+            1 => format!("{self} (+1 cause)"),
+            causes => format!("{self} (+{causes} causes)"),
+        }
+    }
+
+    /// Renders the entire exception tree into a [`String`].
+    ///
+    /// See [`Frame::to_tree_string`].
+    #[must_use]
+    pub fn to_tree_string(&self) -> String {
+        self.frame.to_tree_string()
+    }
+
+    /// Returns the [`Backtrace`] captured when this [`Exn`] was created, if any.
+    ///
+    /// A [`Backtrace`] is only captured if enabled via the `RUST_BACKTRACE` or
+    /// `RUST_LIB_BACKTRACE` environment variables (see [`Backtrace::capture`]). [`Exn::raise`]
+    /// retains the [`Backtrace`] of the raised [`Exn`] rather than capturing a new one, so this
+    /// generally reflects the callsite where the error was first raised.
+    #[cfg(feature = "backtrace")]
+    #[must_use]
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        self.backtrace.as_deref()
+    }
+
+    /// Returns the maximum depth of the exception tree (see [`Frame::depth`]).
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.frame.depth()
+    }
+
+    /// Returns the total number of [`Frame`]s in the exception tree (see [`Frame::count`]).
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.frame.count()
+    }
+
+    /// Returns the [`Error`] at the root cause of this [`Exn`].
+    ///
+    /// The root cause is found by following the first child of each [`Frame`] until reaching a
+    /// [`Frame`] without children. This corresponds to the end of the [`Error::source`] chain of
+    /// [`Frame`]. If the top-level [`Frame`] has no children, its own [`Error`] is returned.
+    #[must_use]
+    pub fn root_cause(&self) -> &(dyn Error + Send + Sync + 'static) {
+        self.frame
+            .spine()
+            .last()
+            .unwrap_or_else(|| unreachable!("spine must not be empty"))
+            .error()
+    }
+
+    /// Returns the location of the [`Frame`] at the root cause of this [`Exn`].
+    ///
+    /// The [`Frame`] is found the same way as in [`Exn::root_cause`]. This is typically where the
+    /// original failure occurred, whereas [`Exn::first_location`] is where the most recent context
+    /// was raised.
+    #[must_use]
+    pub fn root_location(&self) -> &'static Location<'static> {
+        self.frame
+            .spine()
+            .last()
+            .unwrap_or_else(|| unreachable!("spine must not be empty"))
+            .location
+    }
+
+    /// Returns an [`Iterator`] over the primary chain of [`Error`]s in this [`Exn`].
+    ///
+    /// The chain starts with the top-level error and follows the first child of each [`Frame`],
+    /// ending with the [root cause]. This mirrors the [`Error::source`] chain of [`Frame`] and the
+    /// coercion performed by [`repr::List`].
+    ///
+    /// Unlike [`Frame::iter`], which visits every [`Frame`] in the tree, this only visits the
+    /// first child at each level, so other branches are skipped.
+    ///
+    /// [root cause]: Exn::root_cause
+    /// [`repr::List`]: crate::repr::List
+    pub fn chain(&self) -> impl Iterator<Item = &(dyn Error + Send + Sync + 'static)> {
+        self.frame.spine().map(Frame::error)
+    }
+
+    /// Returns the location of the top-level [`Frame`] of this [`Exn`].
+    ///
+    /// This is where the most recent context was raised.
+    #[must_use]
+    pub fn first_location(&self) -> &'static Location<'static> {
+        self.frame.location
+    }
+
+    /// Returns an [`Iterator`] over the locations of all [`Frame`]s in this [`Exn`].
+    ///
+    /// [`Frame`]s are visited in the same order as [`Frame::iter`], so the first location is
+    /// [`Exn::first_location`].
+    pub fn all_locations(&self) -> impl Iterator<Item = &'static Location<'static>> {
+        self.frame.iter().map(Frame::location)
+    }
+
+    /// Returns an [`Iterator`] over the messages and locations of all [`Frame`]s in this [`Exn`].
+    ///
+    /// See [`Frame::entries`].
+    pub fn entries(&self) -> impl Iterator<Item = (String, &'static Location<'static>)> {
+        self.frame.entries()
+    }
+
+    /// Returns the messages of all [`Frame`]s in this [`Exn`].
+    ///
+    /// See [`Frame::messages`].
+    #[must_use]
+    pub fn messages(&self) -> Vec<String> {
+        self.frame.messages()
+    }
+
+    /// Returns a single-line summary of this [`Exn`] suitable for a log entry.
+    ///
+    /// This is [`Exn::log_line_with`] with a maximum length of [`LOG_LINE_MAX_LEN`].
+    #[must_use]
+    pub fn log_line(&self) -> String {
+        self.log_line_with(LOG_LINE_MAX_LEN)
+    }
+
+    /// Returns a single-line summary of this [`Exn`] of at most `max_len` characters (excluding the
+    /// ellipsis).
+    ///
+    /// Like [`repr::Compact`], this follows the first child of each [`Frame`], but the messages are
+    /// joined with ` <- ` and any line breaks (`\n` or `\r`) within them are replaced with spaces.
+    /// If the summary is longer than `max_len` characters, it is cut off and followed by an
+    /// ellipsis (`…`). Messages past the cutoff are not formatted.
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use exn::ErrorExt;
+    ///
+    /// let child = io::Error::other("child").raise();
+    /// let exn = child.raise(io::Error::other("parent"));
+    /// assert_eq!(exn.log_line(), "parent <- child");
+    /// assert_eq!(exn.log_line_with(9), "parent <-…");
+    /// ```
+    ///
+    /// [`repr::Compact`]: crate::repr::Compact
+    #[must_use]
+    pub fn log_line_with(&self, max_len: usize) -> String {
+        let mut line = String::new();
+        let mut writer = Truncator::new(&mut line, max_len).single_line();
+        for (i, frame) in self.frame.spine().enumerate() {
+            let separator = if i > 0 { " <- " } else { "" };
+            if write!(writer, "{separator}{frame}").is_err() {
+                break;
+            }
+        }
+        if writer.truncated {
+            line.push('…');
+        }
+        line
+    }
+
+    /// Returns a reference to the first error of type `T` in this [`Exn`]'s tree, if any.
+    ///
+    /// [`Frame`]s are searched in depth-first preorder (see [`Frame::iter`]).
+    ///
+    /// # Type erasure
+    ///
+    /// Only errors that were given to [`Exn`] directly (e.g., via [`Exn::new`], [`Exn::raise`], or
+    /// [`Exn::raise_all`]) retain their concrete types. The [source `Error`s] of those errors are
+    /// stored as type-erased [`Frame`]s that only retain their [`String`] representations, so they
+    /// can never be downcast.
+    ///
+    /// [source `Error`s]: Error::source
+    #[must_use]
+    pub fn downcast_ref<T: Error + 'static>(&self) -> Option<&T> {
+        self.frame.iter().find_map(Frame::downcast_ref)
+    }
+
+    /// Returns a reference to the first [`Frame`] in this [`Exn`]'s tree whose error is of type
+    /// `T`, if any.
+    ///
+    /// This is useful for reporting the [location] of a specific error. The error itself is
+    /// accessible via [`Frame::downcast_ref`]. The same [type erasure] caveats as
+    /// [`Exn::downcast_ref`] apply.
+    ///
+    /// [location]: Frame::location
+    /// [type erasure]: Exn::downcast_ref
+    #[must_use]
+    pub fn find<T: Error + 'static>(&self) -> Option<&Frame> {
+        self.frame.iter().find(|frame| frame.error().is::<T>())
+    }
+
+    /// Returns the number of [`Frame`]s in this [`Exn`]'s tree whose errors are of type `T`.
+    ///
+    /// The same [type erasure] caveats as [`Exn::downcast_ref`] apply, so [`Frame`]s whose types
+    /// were erased are never counted; see [`Exn::count_erased`] to count them instead.
+    ///
+    /// ```
+    /// use std::{fmt, io};
+    ///
+    /// use exn::{ErrorExt, Exn};
+    ///
+    /// let children = [io::Error::other("a").raise(), io::Error::other("b").raise()];
+    /// let exn = Exn::raise_all(children, fmt::Error);
+    /// assert_eq!(exn.count_by::<io::Error>(), 2);
+    /// assert_eq!(exn.count_by::<fmt::Error>(), 1);
+    /// ```
+    ///
+    /// [type erasure]: Exn::downcast_ref
+    #[must_use]
+    pub fn count_by<T: Error + 'static>(&self) -> usize {
+        self.frame
+            .iter()
+            .filter(|frame| frame.error().is::<T>())
+            .count()
+    }
+
+    /// Returns the number of [`Frame`]s in this [`Exn`]'s tree whose errors were type-erased.
+    ///
+    /// These are the [`Frame`]s that were created from [source `Error`s] or otherwise only retain
+    /// the [`String`] representations of their errors (see [`Exn::downcast_ref`]).
+    ///
+    /// [source `Error`s]: Error::source
+    #[must_use]
+    pub fn count_erased(&self) -> usize {
+        self.frame
+            .iter()
+            .filter(|frame| frame.error().is::<SourceError>())
+            .count()
+    }
+
+    /// Returns `true` if any error in this [`Exn`]'s tree is of type `T`.
+    ///
+    /// This is equivalent to `self.downcast_ref::<T>().is_some()`, so the same [type erasure]
+    /// caveats apply.
+    ///
+    /// [type erasure]: Exn::downcast_ref
+    #[must_use]
+    pub fn is<T: Error + 'static>(&self) -> bool {
+        self.downcast_ref::<T>().is_some()
+    }
+
+    /// Converts this [`Exn`] into its top-level error, discarding all other [`Frame`]s.
+    #[must_use]
+    pub fn into_inner(self) -> E {
+        downcast_owned(self.frame.error)
+    }
+
+    /// Converts this [`Exn`] into its top-level error if it is of type `T`, discarding all other
+    /// [`Frame`]s.
+    ///
+    /// Since the top-level error is always of type `E`, this succeeds if and only if `T` is `E`, in
+    /// which case it is equivalent to [`Exn::into_inner`]. Unlike [`Exn::downcast_ref`], the
+    /// descendants of the top-level [`Frame`] are never considered. This is mostly useful in
+    /// generic code where `E` is not known to be `T`.
+    ///
+    /// ```
+    /// use std::{fmt, io};
+    ///
+    /// use exn::ErrorExt;
+    ///
+    /// let exn = fmt::Error.raise().raise(io::Error::other("parent"));
+    /// let exn = exn.downcast::<fmt::Error>().unwrap_err();
+    /// assert_eq!(exn.downcast::<io::Error>().unwrap().to_string(), "parent");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If `T` is not `E`, `self` is returned unchanged.
+    pub fn downcast<T: Error + Send + Sync + 'static>(self) -> Result<T, Self> {
+        if self.frame.error.is::<T>() {
+            Ok(downcast_owned(self.frame.error))
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Converts this [`Exn`] into its top-level error and the children of its top-level [`Frame`].
+    ///
+    /// This is like [`Frame::consume`], except that the error retains its type. The top-level
+    /// [`Frame`]'s location and attachments are discarded. The result can be reassembled via
+    /// [`Exn::with_siblings`]:
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use exn::{ErrorExt, Exn};
+    ///
+    /// let exn = io::Error::other("child")
+    ///     .raise()
+    ///     .raise(io::Error::other("parent"));
+    /// let (error, children) = exn.split();
+    /// let exn = Exn::new(error).with_siblings(children);
+    /// assert_eq!(exn.frame().children()[0].to_string(), "child");
+    /// ```
+    #[must_use]
+    pub fn split(self) -> (E, Vec<Frame>) {
+        (downcast_owned(self.frame.error), self.frame.children)
+    }
+
+    /// Collapses each chain of single children in the exception tree into one [`Frame`].
+    ///
+    /// Each [`Frame`] with exactly one child is merged with that child into a type-erased
+    /// [`Frame`] whose message is `parent: child`. The merged [`Frame`] has the location of the
+    /// child (i.e., the deepest location in the chain), the attachments of both, and the
+    /// [level], [code], [span], and [help] of the parent if set or else those of the child. This is
+    /// repeated until every [`Frame`] has either zero or multiple children.
+    ///
+    /// Since the top-level [`Frame`] may be merged, the result is a [`Frame`] rather than an
+    /// [`Exn`].
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use exn::ErrorExt;
+    ///
+    /// let child = io::Error::other("child").raise();
+    /// let frame = child.raise(io::Error::other("parent")).flatten();
+    /// assert_eq!(frame.to_string(), "parent: child");
+    /// assert!(frame.children().is_empty());
+    /// ```
+    ///
+    /// [level]: Frame::level
+    /// [code]: Frame::code
+    /// [span]: Frame::span
+    /// [help]: Frame::help
+    #[must_use]
+    pub fn flatten(self) -> Frame {
+        let mut frame = *self.frame;
+        frame.flatten();
+        frame
+    }
+
+    /// Converts this [`Exn`] into a flat list of all of its [`Frame`]s in preorder.
+    ///
+    /// Every [`Frame`] in the result has no children, so the structure of the exception tree is
+    /// lost. The order of the result matches [`Frame::iter`].
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use exn::ErrorExt;
+    ///
+    /// let child = io::Error::other("child").raise();
+    /// let frames = child.raise(io::Error::other("parent")).into_frames();
+    /// assert_eq!(frames.len(), 2);
+    /// assert!(frames.iter().all(|frame| frame.children().is_empty()));
+    /// ```
+    #[must_use]
+    pub fn into_frames(self) -> Vec<Frame> {
+        let mut frames = Vec::new();
+        let mut stack = vec![*self.frame];
+        while let Some(mut frame) = stack.pop() {
+            let children = core::mem::take(&mut frame.children);
+            frames.push(frame);
+            stack.extend(children.into_iter().rev());
+        }
+        frames
+    }
+
+    /// Converts this [`Exn`] into its underlying exception frame.
+    #[deprecated(since = "0.3.1", note = "Use `Frame::from` instead")]
+    #[must_use]
+    pub fn into_frame(self) -> Frame {
+        *self.frame
+    }
+}
+
+/// Downcasts an `error` that is known to be of type `E`.
+fn downcast_owned<E: Error + Send + Sync + 'static>(
+    error: Box<dyn Error + Send + Sync + 'static>,
+) -> E {
+    *error
+        .downcast()
+        .unwrap_or_else(|_| unreachable!("error type must match"))
+}
+
+#[cfg(feature = "backtrace")]
+fn capture_backtrace() -> Option<Arc<Backtrace>> {
+    let backtrace = Backtrace::capture();
+    (backtrace.status() == BacktraceStatus::Captured).then(|| Arc::new(backtrace))
+}
+
+impl<E: Error + Send + Sync + 'static> Deref for Exn<E> {
+    type Target = E;
+
+    fn deref(&self) -> &Self::Target {
+        self.frame
+            .error()
+            .downcast_ref()
+            .unwrap_or_else(|| unreachable!("error type must match"))
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> Debug for Exn<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if f.alternate() {
+            f.debug_struct("Exn")
+                .field("frame", self.frame())
+                .finish_non_exhaustive()
+        } else {
+            self.frame.debug_full(f)
+        }
+    }
+}
+
+/// Displays the top-level error, followed by any [amendments].
+///
+/// The alternate form (`{:#}`) displays the entire exception tree, like [`Frame::debug_full`].
+///
+/// [amendments]: Exn::amend
+impl<E: Error + Send + Sync + 'static> Display for Exn<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if f.alternate() {
+            self.frame.debug_full(f)
+        } else {
+            Display::fmt(&self.frame, f)
+        }
+    }
+}
+
+/// Clones the top-level error via [`Clone`].
+///
+/// The errors of all other [`Frame`]s are type-erased in the clone, but their [`String`]
+/// representations, locations, and attachments are retained.
+impl<E: Error + Clone + Send + Sync + 'static> Clone for Exn<E> {
+    fn clone(&self) -> Self {
+        let mut frame = self.frame.clone_erased();
+        frame.error = Box::new((**self).clone());
+        Self {
+            frame: Box::new(frame),
+            #[cfg(feature = "backtrace")]
+            backtrace: self.backtrace.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, E> From<T> for Exn<E>
+where
+    T: Error + Into<E>,
+    E: Error + Send + Sync + 'static,
+{
+    #[track_caller]
+    fn from(error: T) -> Self {
+        Exn::new(error.into())
+    }
+}
+
+/// Aggregates [`Exn`]s as the children of a new [`Exn`] whose error is [`E::default()`].
+///
+/// This is equivalent to [`Exn::raise_all`] with [`E::default()`] as the error, so an empty
+/// iterator yields an [`Exn`] without children rather than panicking. Since [`Iterator::collect`]
+/// does not track its caller, the [location] of the new [`Exn`] is not that of the call site; use
+/// [`Exn::raise_all`] directly where the location matters.
+///
+/// ```
+/// use exn::Exn;
+///
+/// #[derive(Debug, Default, thiserror::Error)]
+/// #[error("multiple failures")]
+/// struct Failures;
+///
+/// let exns = [Exn::new(Failures), Exn::new(Failures)];
+/// let exn: Exn<Failures> = exns.into_iter().collect();
+/// assert_eq!(exn.frame().children().len(), 2);
+/// ```
+///
+/// [`E::default()`]: Default::default
+/// [location]: Frame::location
+impl<E> FromIterator<Exn<E>> for Exn<E>
+where
+    E: Default + Error + Send + Sync + 'static,
+{
+    fn from_iter<I: IntoIterator<Item = Exn<E>>>(iter: I) -> Self {
+        Exn::raise_all(iter, E::default())
+    }
+}
+
+/// Fowler–Noll–Vo (FNV-1a) [`Hasher`], since `core` provides none
+struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+/// Type-erased error that only retains the [`String`] representation of the original
+struct SourceError(String);
+
+impl Debug for SourceError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Display for SourceError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Error for SourceError {}
+
+/// Node in an exception tree
+pub struct Frame {
+    /// Originating error
+    error: Box<dyn Error + Send + Sync + 'static>,
+
+    /// Source location where this frame was created
+    location: &'static Location<'static>,
+
+    /// Child frames that provide additional context or source error information
+    children: Vec<Frame>,
+
+    /// Rarely used data, which is boxed to keep `Exn` small
+    extras: Option<Box<Extras>>,
+
+    /// Time at which this frame was created
+    #[cfg(feature = "timestamps")]
+    timestamp: Option<SystemTime>,
+
+    /// Thread on which this frame was created
+    #[cfg(feature = "threads")]
+    thread: Option<Arc<ThreadInfo>>,
+}
+
+/// Rarely used data of a [`Frame`]
+#[derive(Clone, Default, Eq, Hash, PartialEq)]
+struct Extras {
+    /// Key-value pairs that provide additional context
+    attachments: Vec<(Cow<'static, str>, String)>,
+
+    /// Number of identical sibling frames that this frame represents if not one
+    multiplicity: Option<usize>,
+
+    /// Severity of the frame
+    level: Option<Level>,
+
+    /// Application-defined error code (e.g., a process exit code)
+    code: Option<i32>,
+
+    /// Extra details appended to the message of the frame, separated by `: `
+    amendments: Option<String>,
+
+    /// Labeled range of source input that the frame refers to
+    span: Option<Span>,
+
+    /// User-facing suggestion for resolving the error
+    help: Option<String>,
+
+    /// Location at which the frame was originally created if it was converted from [`FrameData`]
+    #[cfg(feature = "serde")]
+    origin: Option<Origin>,
+
+    /// Current `tracing` span when the frame was created
+    #[cfg(feature = "tracing")]
+    tracing_span: TracingSpan,
+}
+
+impl Extras {
+    /// Extras of a [`Frame`] that has none
+    const NONE: Self = Self {
+        attachments: Vec::new(),
+        multiplicity: None,
+        level: None,
+        code: None,
+        amendments: None,
+        span: None,
+        help: None,
+        #[cfg(feature = "serde")]
+        origin: None,
+        #[cfg(feature = "tracing")]
+        tracing_span: TracingSpan(None),
+    };
+
+    /// Returns `true` if these [`Extras`] hold nothing, so they need not be allocated.
+    fn is_empty(&self) -> bool {
+        #[cfg(feature = "tracing")]
+        if self.tracing_span.0.is_some() {
+            return false;
+        }
+        *self == Self::NONE
+    }
+}
+
+/// Owned location of a [`Frame`] converted from [`FrameData`]
+#[cfg(feature = "serde")]
+#[derive(Clone, Eq, Hash, PartialEq)]
+struct Origin {
+    file: String,
+    line: u32,
+    column: u32,
+}
+
+/// File, line, and column at which a [`Frame`] was created
+///
+/// Unlike [`Frame::location`], this is the original location of a [`Frame`] converted from
+/// [`FrameData`].
+#[derive(Clone, Copy)]
+pub(crate) struct SourceLocation<'a> {
+    pub(crate) file: &'a str,
+    pub(crate) line: u32,
+    pub(crate) column: u32,
+}
+
+impl Display for SourceLocation<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+/// [`tracing::Span`] that is ignored by comparisons and hashing (see [`Frame`]'s [`PartialEq`])
+#[cfg(feature = "tracing")]
+#[derive(Clone, Default)]
+struct TracingSpan(Option<tracing::Span>);
+
+#[cfg(feature = "tracing")]
+impl PartialEq for TracingSpan {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl Eq for TracingSpan {}
+
+#[cfg(feature = "tracing")]
+impl Hash for TracingSpan {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+/// Identity of the thread on which a [`Frame`] was created
+#[cfg(feature = "threads")]
+#[derive(Debug)]
+struct ThreadInfo {
+    id: ThreadId,
+    name: Option<String>,
+}
+
+#[cfg(feature = "threads")]
+impl ThreadInfo {
+    /// Returns the identity of the current thread, which is shared by all [`Frame`]s created on it.
+    ///
+    /// This is [`None`] while the thread-local storage of the current thread is being destroyed.
+    fn current() -> Option<Arc<Self>> {
+        std::thread_local! {
+            static CURRENT: Arc<ThreadInfo> = {
+                let thread = thread::current();
+                Arc::new(ThreadInfo {
+                    id: thread.id(),
+                    name: thread.name().map(String::from),
+                })
+            };
+        }
+
+        CURRENT.try_with(Arc::clone).ok()
+    }
+}
+
+impl Frame {
+    #[track_caller]
+    fn new<E: Error + Send + Sync + 'static>(error: E) -> Self {
+        Self::new_at(error, Location::caller())
+    }
+
+    /// Records the current [`tracing::Span`] unless it is disabled (e.g., without a subscriber).
+    ///
+    /// This is only done for the top-level [`Frame`] of a new [`Exn`], since storing the span
+    /// allocates [`Extras`].
+    #[cfg_attr(not(feature = "tracing"), expect(unused_mut))]
+    fn with_tracing_span(mut self) -> Self {
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::Span::current();
+            if !span.is_none() {
+                self.extras_mut().tracing_span = TracingSpan(Some(span));
+            }
+        }
+        self
+    }
+
+    fn new_at<E: Error + Send + Sync + 'static>(
+        error: E,
+        location: &'static Location<'static>,
+    ) -> Self {
+        Self::new_at_with(error, location, |source| {
+            Box::new(SourceError(source.to_string()))
+        })
+    }
+
+    /// Creates a new [`Frame`] whose sources are converted to errors by `convert`.
+    fn new_at_with<E: Error + Send + Sync + 'static>(
+        error: E,
+        location: &'static Location<'static>,
+        convert: fn(&(dyn Error + 'static)) -> Box<dyn Error + Send + Sync + 'static>,
+    ) -> Self {
+        fn walk(
+            error: &dyn Error,
+            location: &'static Location<'static>,
+            convert: fn(&(dyn Error + 'static)) -> Box<dyn Error + Send + Sync + 'static>,
+        ) -> Vec<Frame> {
+            error
+                .source()
+                .map(|source| {
+                    Frame::from_parts(convert(source), location, walk(source, location, convert))
+                })
+                .into_iter()
+                .collect()
+        }
+
+        // The chain is walked eagerly rather than on first access to the children. Deferring it
+        // would need a cell in every `Frame` (a `OnceLock` with `std`, or a hand-rolled one
+        // without), and `Frame::children_mut` would have to force it anyway. The `chain` benchmark
+        // shows that the walk is a minor share of the cost of rendering the chain, which is the
+        // common fate of an `Exn`.
+        //
+        // Most errors have no source, so avoid walking the (empty) chain for them.
+        let children = if error.source().is_some() {
+            walk(&error, location, convert)
+        } else {
+            Vec::new()
+        };
+        Self::from_parts(Box::new(error), location, children)
+    }
+
+    fn from_parts(
+        error: Box<dyn Error + Send + Sync + 'static>,
+        location: &'static Location<'static>,
+        children: Vec<Frame>,
+    ) -> Self {
+        Self {
+            error,
+            location,
+            children,
+            extras: None,
+            #[cfg(feature = "timestamps")]
+            timestamp: Some(SystemTime::now()),
+            #[cfg(feature = "threads")]
+            thread: ThreadInfo::current(),
+        }
+    }
+
+    fn extras(&self) -> &Extras {
+        // `Extras::NONE` cannot be promoted to a `'static` reference since `Vec` implements `Drop`.
+        static NONE: Extras = Extras::NONE;
+        self.extras.as_deref().unwrap_or(&NONE)
+    }
+
+    fn extras_mut(&mut self) -> &mut Extras {
+        self.extras.get_or_insert_default()
+    }
+
+    /// Returns the [`Error`] that occurred at this frame.
+    #[must_use]
+    pub fn error(&self) -> &(dyn Error + Send + Sync + 'static) {
+        &*self.error
+    }
+
+    /// Returns a reference to the [`Error`] that occurred at this frame if it is of type `T`.
+    ///
+    /// See [`Exn::downcast_ref`] for details about which errors retain their concrete types.
+    #[must_use]
+    pub fn downcast_ref<T: Error + 'static>(&self) -> Option<&T> {
+        self.error.downcast_ref()
+    }
+
+    /// Replaces the [`Error`] of this [`Frame`], returning the previous one.
+    ///
+    /// The location, children, and other metadata of this [`Frame`] are unchanged.
+    pub fn replace_error<T: Error + Send + Sync + 'static>(
+        &mut self,
+        error: T,
+    ) -> Box<dyn Error + Send + Sync + 'static> {
+        core::mem::replace(&mut self.error, Box::new(error))
+    }
+
+    /// Returns the source location where this frame was created.
+    ///
+    /// For a [`Frame`] converted from [`FrameData`], this is the callsite of the conversion, but
+    /// its original location is rendered and serialized instead.
+    #[must_use]
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    /// Returns the location to render and serialize for this [`Frame`].
+    pub(crate) fn source_location(&self) -> SourceLocation<'_> {
+        #[cfg(feature = "serde")]
+        if let Some(origin) = &self.extras().origin {
+            return SourceLocation {
+                file: &origin.file,
+                line: origin.line,
+                column: origin.column,
+            };
+        }
+        SourceLocation {
+            file: self.location.file(),
+            line: self.location.line(),
+            column: self.location.column(),
+        }
+    }
+
+    /// Returns all child [`Frame`]s.
+    #[must_use]
+    pub fn children(&self) -> &[Frame] {
+        &self.children
+    }
+
+    /// Returns a mutable reference to the child [`Frame`]s.
+    pub fn children_mut(&mut self) -> &mut Vec<Frame> {
+        &mut self.children
+    }
 
-        impl Debug for SourceError {
-            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-                Debug::fmt(&self.0, f)
+    /// Calls `f` with mutable access to this [`Frame`] and each of its descendants in preorder.
+    ///
+    /// Each [`Frame`] is visited before its children, so `f` may add, remove, or reorder the
+    /// children of the [`Frame`] it is given (see [`Frame::children_mut`]), and the resulting
+    /// children are visited afterward. Modifying any other part of the tree from within `f` is not
+    /// possible.
+    pub fn walk_mut<F: FnMut(&mut Self)>(&mut self, mut f: F) {
+        fn walk(frame: &mut Frame, f: &mut impl FnMut(&mut Frame)) {
+            f(frame);
+            for child in &mut frame.children {
+                walk(child, f);
             }
         }
 
-        impl Display for SourceError {
-            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-                Display::fmt(&self.0, f)
+        walk(self, &mut f);
+    }
+
+    /// Visits this [`Frame`] and all of its descendants with `visitor`.
+    ///
+    /// [`FrameVisitor::enter`] and [`FrameVisitor::leave`] are called for each [`Frame`] before and
+    /// after its children, respectively, with this [`Frame`] at a depth of zero.
+    pub fn accept<V: FrameVisitor + ?Sized>(&self, visitor: &mut V) {
+        fn visit<V: FrameVisitor + ?Sized>(frame: &Frame, visitor: &mut V, depth: usize) {
+            visitor.enter(frame, depth);
+            for child in &frame.children {
+                visit(child, visitor, depth + 1);
             }
+            visitor.leave(frame, depth);
         }
 
-        impl Error for SourceError {}
+        visit(self, visitor, 0);
+    }
 
-        fn walk(error: &dyn Error, location: &'static Location<'static>) -> Vec<Frame> {
-            error
-                .source()
-                .map(|source| Frame {
-                    error: Box::new(SourceError(source.to_string())),
-                    location,
-                    children: walk(source, location),
-                })
-                .into_iter()
-                .collect()
-        }
+    /// Returns all key-value pairs attached to this [`Frame`].
+    ///
+    /// See [`Exn::attach`].
+    #[must_use]
+    pub fn attachments(&self) -> &[(Cow<'static, str>, String)] {
+        &self.extras().attachments
+    }
 
-        let location = Location::caller();
-        let children = walk(&error, location);
-        let frame = Frame {
-            error: Box::new(error),
-            location,
-            children,
-        };
+    /// Returns the [`Level`] of this [`Frame`], if any.
+    ///
+    /// See [`Exn::with_level`].
+    #[must_use]
+    pub fn level(&self) -> Option<Level> {
+        self.extras().level
+    }
 
-        Self {
-            frame: Box::new(frame),
-            phantom: PhantomData,
-        }
+    /// Returns the error code of this [`Frame`], if any.
+    ///
+    /// See [`Exn::with_code`].
+    #[must_use]
+    pub fn code(&self) -> Option<i32> {
+        self.extras().code
     }
 
-    /// Creates a new [`Exn`] with the given `error` and its `children`.
-    #[track_caller]
-    pub fn raise_all<T, I>(children: I, error: E) -> Self
-    where
-        T: Error + Send + Sync + 'static,
-        I: IntoIterator,
-        I::Item: Into<Exn<T>>,
-    {
-        let mut new_exn = Exn::new(error);
-        // Note: We can't use `Vec::extend` since `#[track_caller]` on closures is currently
-        // unstable.
-        for child in children {
-            new_exn.frame.children.push(*child.into().frame);
-        }
-        new_exn
+    /// Returns the [`Span`] of this [`Frame`], if any.
+    ///
+    /// See [`Exn::with_span`].
+    #[must_use]
+    pub fn span(&self) -> Option<&Span> {
+        self.extras().span.as_ref()
     }
 
-    /// Creates a new [`Exn`] where `self` is its child.
-    #[track_caller]
-    pub fn raise<T: Error + Send + Sync + 'static>(self, error: T) -> Exn<T> {
-        let mut new_exn = Exn::new(error);
-        new_exn.frame.children.push(*self.frame);
-        new_exn
+    /// Returns the time at which this [`Frame`] was created, if known.
+    ///
+    /// This is [`None`] for [`Frame`]s that do not correspond to a single creation time, such as
+    /// the marker created by [`Exn::prune_depth`] and [`Frame`]s converted from `FrameData`.
+    #[cfg(feature = "timestamps")]
+    #[must_use]
+    pub fn timestamp(&self) -> Option<SystemTime> {
+        self.timestamp
     }
 
-    /// Returns a reference to the underlying exception frame.
+    /// Returns the name of the thread on which this [`Frame`] was created, if known and named.
+    ///
+    /// Like [`Frame::thread_id`], this is [`None`] for [`Frame`]s that do not correspond to a
+    /// single thread.
+    #[cfg(feature = "threads")]
     #[must_use]
-    pub fn frame(&self) -> &Frame {
-        &self.frame
+    pub fn thread_name(&self) -> Option<&str> {
+        self.thread
+            .as_ref()
+            .and_then(|thread| thread.name.as_deref())
     }
 
-    /// Converts this [`Exn`] into its underlying exception frame.
-    #[deprecated(since = "0.3.1", note = "Use `Frame::from` instead")]
+    /// Returns the ID of the thread on which this [`Frame`] was created, if known.
+    ///
+    /// This is [`None`] for [`Frame`]s that do not correspond to a single thread, such as the
+    /// marker created by [`Exn::prune_depth`] and [`Frame`]s converted from `FrameData`.
+    #[cfg(feature = "threads")]
     #[must_use]
-    pub fn into_frame(self) -> Frame {
-        *self.frame
+    pub fn thread_id(&self) -> Option<ThreadId> {
+        self.thread.as_ref().map(|thread| thread.id)
     }
-}
 
-impl<E: Error + Send + Sync + 'static> Deref for Exn<E> {
-    type Target = E;
+    /// Returns the [`tracing::Span`] that was current when this [`Frame`] was created, if any.
+    ///
+    /// This associates the [`Frame`] with its originating span even after the [`Exn`] has been
+    /// propagated out of it. The span is only recorded for the top-level [`Frame`] of an [`Exn`]
+    /// created via [`Exn::new`] (or its variants), where the error originated, so this is [`None`]
+    /// for [`Frame`]s created via [`Exn::raise`] and for the sources of an error. It is also
+    /// [`None`] if no span was entered (or no subscriber was interested in it).
+    #[cfg(feature = "tracing")]
+    #[must_use]
+    pub fn tracing_span(&self) -> Option<&tracing::Span> {
+        self.extras().tracing_span.0.as_ref()
+    }
 
-    fn deref(&self) -> &Self::Target {
-        self.frame
-            .error()
-            .downcast_ref()
-            .unwrap_or_else(|| unreachable!("error type must match"))
+    /// Returns the help text of this [`Frame`], if any.
+    ///
+    /// See [`Exn::with_help`].
+    #[must_use]
+    pub fn help(&self) -> Option<&str> {
+        self.extras().help.as_deref()
     }
-}
 
-impl<E: Error + Send + Sync + 'static> Debug for Exn<E> {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        if f.alternate() {
-            f.debug_struct("Exn")
-                .field("frame", self.frame())
-                .finish_non_exhaustive()
-        } else {
-            self.frame.debug_full(f)
-        }
+    /// Returns the number of identical sibling [`Frame`]s that this [`Frame`] represents.
+    ///
+    /// This is `1` unless this [`Frame`] was collapsed by [`Exn::dedup_children`].
+    #[must_use]
+    pub fn multiplicity(&self) -> usize {
+        self.extras().multiplicity.unwrap_or(1)
     }
-}
 
-impl<E: Error + Send + Sync + 'static> Display for Exn<E> {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        Display::fmt(&**self, f)
+    fn set_multiplicity(&mut self, multiplicity: usize) {
+        if multiplicity != 1 {
+            self.extras_mut().multiplicity = Some(multiplicity);
+        } else if let Some(extras) = &mut self.extras {
+            extras.multiplicity = None;
+        }
     }
-}
 
-impl<T, E> From<T> for Exn<E>
-where
-    T: Error + Into<E>,
-    E: Error + Send + Sync + 'static,
-{
-    #[track_caller]
-    fn from(error: T) -> Self {
-        Exn::new(error.into())
+    /// Returns the maximum depth of any descendant of this [`Frame`].
+    ///
+    /// A [`Frame`] without children has a depth of `0`, and each level of descendants increments
+    /// the depth by `1`.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.children
+            .iter()
+            .map(|child| child.depth() + 1)
+            .max()
+            .unwrap_or(0)
     }
-}
 
-/// Node in an exception tree
-pub struct Frame {
-    /// Originating error
-    error: Box<dyn Error + Send + Sync + 'static>,
+    /// Returns the total number of [`Frame`]s in this tree, including `self`.
+    #[must_use]
+    pub fn count(&self) -> usize {
+        1 + self.children.iter().map(Self::count).sum::<usize>()
+    }
 
-    /// Source location where this frame was created
-    location: &'static Location<'static>,
+    /// Returns an [`Iterator`] over this [`Frame`] and all of its descendants.
+    ///
+    /// [`Frame`]s are visited in depth-first preorder: each [`Frame`] is yielded before its
+    /// children, and children are yielded in order.
+    pub fn iter(&self) -> impl Iterator<Item = &Frame> {
+        let mut stack = vec![self];
+        core::iter::from_fn(move || {
+            let frame = stack.pop()?;
+            stack.extend(frame.children.iter().rev());
+            Some(frame)
+        })
+    }
 
-    /// Child frames that provide additional context or source error information
-    children: Vec<Frame>,
-}
+    /// Returns an [`Iterator`] over the messages and locations of this [`Frame`] and all of its
+    /// descendants.
+    ///
+    /// Each message is the [`Display`] representation of the error. [`Frame`]s are visited in the
+    /// same order as [`Frame::iter`].
+    pub fn entries(&self) -> impl Iterator<Item = (String, &'static Location<'static>)> {
+        self.iter().map(|frame| (frame.to_string(), frame.location))
+    }
 
-impl Frame {
-    /// Returns the [`Error`] that occurred at this frame.
+    /// Returns the messages of this [`Frame`] and all of its descendants.
+    ///
+    /// Each message is the [`Display`] representation of the error. [`Frame`]s are visited in the
+    /// same order as [`Frame::iter`]. This is convenient for assertions in tests:
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use exn::ErrorExt;
+    ///
+    /// let child = io::Error::other("child").raise();
+    /// let exn = child.raise(io::Error::other("parent"));
+    /// assert_eq!(exn.frame().messages(), ["parent", "child"]);
+    /// ```
     #[must_use]
-    pub fn error(&self) -> &(dyn Error + Send + Sync + 'static) {
-        &*self.error
+    pub fn messages(&self) -> Vec<String> {
+        self.iter().map(ToString::to_string).collect()
     }
 
-    /// Returns the source location where this frame was created.
-    #[must_use]
-    pub fn location(&self) -> &'static Location<'static> {
-        self.location
+    /// Returns an [`Iterator`] over this [`Frame`] and its first descendant at each level.
+    pub(crate) fn spine(&self) -> impl Iterator<Item = &Frame> {
+        core::iter::successors(Some(self), |frame| frame.children.first())
     }
 
-    /// Returns all child [`Frame`]s.
-    #[must_use]
-    pub fn children(&self) -> &[Frame] {
-        &self.children
+    /// Replaces the error of this [`Frame`], which must be of type `E`, with `f(error)`.
+    fn map_error<E, T>(self, f: impl FnOnce(E) -> T) -> Self
+    where
+        E: Error + Send + Sync + 'static,
+        T: Error + Send + Sync + 'static,
+    {
+        let error = downcast_owned(self.error);
+        Self {
+            error: Box::new(f(error)),
+            ..self
+        }
+    }
+
+    /// Clones this [`Frame`] and its children recursively, erasing the types of all errors.
+    fn clone_erased(&self) -> Self {
+        Self {
+            error: Box::new(SourceError(self.error.to_string())),
+            location: self.location,
+            children: self.children.iter().map(Self::clone_erased).collect(),
+            extras: self.extras.clone(),
+            #[cfg(feature = "timestamps")]
+            timestamp: self.timestamp,
+            #[cfg(feature = "threads")]
+            thread: self.thread.clone(),
+        }
+    }
+
+    /// Replaces all descendants deeper than `max_depth` with a single marker [`Frame`].
+    fn prune_depth(&mut self, max_depth: usize) {
+        if let Some(max_depth) = max_depth.checked_sub(1) {
+            for child in &mut self.children {
+                child.prune_depth(max_depth);
+            }
+            return;
+        }
+
+        let count: usize = self.children.iter().map(Self::count).sum();
+        if count > 0 {
+            let plural = if count == 1 { "" } else { "s" };
+            let error = SourceError(format!("... ({count} more frame{plural})"));
+            #[cfg_attr(
+                not(any(feature = "threads", feature = "timestamps")),
+                expect(unused_mut)
+            )]
+            let mut marker = Self::from_parts(Box::new(error), self.location, Vec::new());
+            #[cfg(feature = "timestamps")]
+            {
+                marker.timestamp = None;
+            }
+            #[cfg(feature = "threads")]
+            {
+                marker.thread = None;
+            }
+            self.children = vec![marker];
+        }
+    }
+
+    /// Merges chains of single children recursively.
+    fn flatten(&mut self) {
+        while self.children.len() == 1 {
+            let child = self.children.remove(0);
+            self.error = Box::new(SourceError(format!("{self}: {child}")));
+            let (parent_extras, child_extras) = (self.extras(), child.extras());
+            let extras = Extras {
+                attachments: (parent_extras.attachments.iter())
+                    .chain(&child_extras.attachments)
+                    .cloned()
+                    .collect(),
+                multiplicity: parent_extras.multiplicity,
+                level: parent_extras.level.or(child_extras.level),
+                code: parent_extras.code.or(child_extras.code),
+                amendments: None,
+                span: (parent_extras.span.as_ref())
+                    .or(child_extras.span.as_ref())
+                    .cloned(),
+                help: (parent_extras.help.as_ref())
+                    .or(child_extras.help.as_ref())
+                    .cloned(),
+                #[cfg(feature = "serde")]
+                origin: child_extras.origin.clone(),
+                #[cfg(feature = "tracing")]
+                tracing_span: TracingSpan(
+                    (parent_extras.tracing_span.0.as_ref())
+                        .or(child_extras.tracing_span.0.as_ref())
+                        .cloned(),
+                ),
+            };
+            self.extras = (!extras.is_empty()).then(|| Box::new(extras));
+            self.location = child.location;
+            self.children = child.children;
+        }
+        for child in &mut self.children {
+            child.flatten();
+        }
+    }
+
+    fn retain_all(&mut self, f: &mut impl FnMut(&Self) -> bool) {
+        self.children.retain(|child| f(child));
+        for child in &mut self.children {
+            child.retain_all(f);
+        }
+    }
+
+    fn sort_children_by_location(&mut self, recursive: bool) {
+        self.children.sort_by(|a, b| {
+            let (a, b) = (a.source_location(), b.source_location());
+            (a.file, a.line, a.column).cmp(&(b.file, b.line, b.column))
+        });
+        if recursive {
+            for child in &mut self.children {
+                child.sort_children_by_location(true);
+            }
+        }
+    }
+
+    fn dedup_children(&mut self) {
+        let mut children: Vec<(Self, usize)> = Vec::with_capacity(self.children.len());
+        // Indices into `children` grouped by hash, so each child is only compared (which
+        // allocates) with the children that it is likely equal to
+        let mut groups: BTreeMap<u64, Vec<usize>> = BTreeMap::new();
+        for mut child in self.children.drain(..) {
+            // Multiplicities are summed rather than compared.
+            let multiplicity = child.multiplicity();
+            child.set_multiplicity(1);
+            let mut hasher = FnvHasher::default();
+            child.hash(&mut hasher);
+            let group = groups.entry(hasher.finish()).or_default();
+            if let Some(&index) = group.iter().find(|&&index| children[index].0 == child) {
+                children[index].1 += multiplicity;
+            } else {
+                group.push(children.len());
+                children.push((child, multiplicity));
+            }
+        }
+        self.children = (children.into_iter())
+            .map(|(mut child, multiplicity)| {
+                child.set_multiplicity(multiplicity);
+                child
+            })
+            .collect();
     }
 
     /// Converts this [`Frame`] into its error and its children.
@@ -201,65 +1845,243 @@ impl Frame {
     /// Performs standard [`Debug`] formatting for only this [`Frame`] (i.e., excluding children).
     #[expect(clippy::missing_errors_doc, reason = "fmt::Result")]
     pub fn debug(&self, f: &mut Formatter) -> fmt::Result {
-        let location = self.location();
-        write!(
-            f,
-            "{}, at {}:{}:{}",
-            self.error(),
-            location.file(),
-            location.line(),
-            location.column()
-        )
+        self.debug_styled(f, &Style::new(ColorSpec::NONE))
+    }
+
+    /// Renders this [`Frame`] and its children recursively into a [`String`].
+    ///
+    /// The result is the same as that of [`Frame::debug_full`]. Note that this differs from
+    /// `format!("{frame:?}")`, which only renders this [`Frame`].
+    #[must_use]
+    pub fn to_tree_string(&self) -> String {
+        struct Full<'a>(&'a Frame);
+
+        impl Debug for Full<'_> {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                self.0.debug_full(f)
+            }
+        }
+
+        format!("{:?}", Full(self))
     }
 
     /// Performs standard [`Debug`] formatting for this [`Frame`] and its children recursively.
     #[expect(clippy::missing_errors_doc, reason = "fmt::Result")]
     pub fn debug_full(&self, f: &mut Formatter) -> fmt::Result {
-        self.debug_recursive(f, true, "")
+        self.debug_tree(f, &Style::new(ColorSpec::NONE))
+    }
+
+    /// Performs the same formatting as [`Frame::debug_full`] with ANSI colors.
+    ///
+    /// The output is identical to that of [`Frame::debug_full`] apart from the ANSI escape codes
+    /// specified by `colors`. In particular, [`ColorSpec::NONE`] produces no escape codes.
+    #[expect(clippy::missing_errors_doc, reason = "fmt::Result")]
+    pub fn debug_full_colored(&self, f: &mut Formatter, colors: &ColorSpec) -> fmt::Result {
+        self.debug_tree(f, &Style::new(*colors))
+    }
+
+    /// Performs the same formatting as [`Frame::debug_full`] with the given `options`.
+    ///
+    /// Options that affect locations only affect how they are printed; the underlying
+    /// [`Location`]s are unchanged. For example, [`DebugOptions::connectors`] can be set to
+    /// [`Connectors::ASCII`] for terminals that cannot render box-drawing characters.
+    ///
+    /// [`Connectors::ASCII`]: crate::Connectors::ASCII
+    #[expect(clippy::missing_errors_doc, reason = "fmt::Result")]
+    pub fn debug_with(&self, f: &mut Formatter, options: &DebugOptions) -> fmt::Result {
+        #[cfg(feature = "std")]
+        let cwd = options
+            .relative
+            .then(std::env::current_dir)
+            .and_then(core::result::Result::ok);
+        #[cfg(feature = "std")]
+        let cwd = cwd.as_deref().and_then(std::path::Path::to_str);
+        #[cfg(not(feature = "std"))]
+        let cwd = None;
+
+        self.debug_tree(f, &Style::with_options(options, cwd))
+    }
+
+    fn debug_styled(&self, f: &mut Formatter, style: &Style) -> fmt::Result {
+        let colors = &style.options.colors;
+        if let Some(level) = self.level() {
+            write!(f, "[{level}] ")?;
+        }
+        write!(f, "{}", Paint(colors.message, style.message(self)))?;
+        if let Some(location) = style.location(self.source_location()) {
+            write!(f, ", at {}", Paint(colors.location, location))?;
+        }
+        if self.multiplicity() > 1 {
+            write!(f, " (x{})", self.multiplicity())?;
+        }
+        #[cfg(feature = "timestamps")]
+        if let Some(timestamp) = self.timestamp.filter(|_| style.options.timestamps) {
+            let since_epoch = timestamp.duration_since(UNIX_EPOCH).unwrap_or_default();
+            let (secs, micros) = (since_epoch.as_secs(), since_epoch.subsec_micros());
+            write!(f, " (t={secs}.{micros:06})")?;
+        }
+        #[cfg(feature = "threads")]
+        if let Some(thread) = self.thread.as_ref().filter(|_| style.options.threads) {
+            match &thread.name {
+                Some(name) => write!(f, " (thread={name})")?,
+                None => write!(f, " (thread={:?})", thread.id)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Formats this [`Frame`] and its children recursively, followed by the help of each.
+    fn debug_tree(&self, f: &mut Formatter, style: &Style) -> fmt::Result {
+        self.debug_recursive(f, style, true, "")?;
+        self.debug_help(f, style)
+    }
+
+    /// Writes the help text of this [`Frame`] and of its descendants that are shown.
+    fn debug_help(&self, f: &mut Formatter, style: &Style) -> fmt::Result {
+        if let Some(help) = self.help() {
+            write!(f, "\nhelp: {help}")?;
+        }
+        for child in self.shown_children(style) {
+            child.debug_help(f, style)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the children of this [`Frame`] that are not hidden by
+    /// [`DebugOptions::max_children`].
+    fn shown_children(&self, style: &Style) -> &[Frame] {
+        let children = self.children();
+        let max_children = style.options.max_children;
+        &children[..max_children.map_or(children.len(), |max| max.min(children.len()))]
     }
 
-    fn debug_recursive(&self, f: &mut Formatter, root: bool, prefix: &str) -> fmt::Result {
-        self.debug(f)?;
+    fn debug_recursive(
+        &self,
+        f: &mut Formatter,
+        style: &Style,
+        root: bool,
+        prefix: &str,
+    ) -> fmt::Result {
+        self.debug_styled(f, style)?;
+        let colors = &style.options.colors;
+        let connectors = &style.options.connectors;
 
         let children = self.children();
         let children_len = children.len();
 
-        for (i, child) in children.iter().enumerate() {
+        let indent = if children_len == 0 {
+            connectors.space
+        } else {
+            connectors.vertical
+        };
+        for (key, value) in self.attachments() {
+            let indent = Paint(colors.connector, format_args!("{prefix}{indent}"));
+            write!(f, "\n{indent}{key} = {value:?}")?;
+        }
+
+        let shown_children = self.shown_children(style);
+        for (i, child) in shown_children.iter().enumerate() {
             let child_children_len = child.children().len();
             if root && children_len == 1 && child_children_len == 1 {
                 // Flatten chains of single children to minimize indentation.
-                write!(f, "\n{prefix}├─ ")?;
-                child.debug_recursive(f, root, prefix)?;
+                write!(
+                    f,
+                    "\n{}",
+                    Paint(
+                        colors.connector,
+                        format_args!("{prefix}{}", connectors.branch)
+                    )
+                )?;
+                child.debug_recursive(f, style, root, prefix)?;
             } else if i < children_len - 1 {
-                write!(f, "\n{prefix}├─ ")?;
-                child.debug_recursive(f, false, &format!("{prefix}│  "))?;
+                write!(
+                    f,
+                    "\n{}",
+                    Paint(
+                        colors.connector,
+                        format_args!("{prefix}{}", connectors.branch)
+                    )
+                )?;
+                child.debug_recursive(
+                    f,
+                    style,
+                    false,
+                    &format!("{prefix}{}", connectors.vertical),
+                )?;
             } else {
-                write!(f, "\n{prefix}└─ ")?;
-                child.debug_recursive(f, false, &format!("{prefix}   "))?;
+                write!(
+                    f,
+                    "\n{}",
+                    Paint(
+                        colors.connector,
+                        format_args!("{prefix}{}", connectors.last)
+                    )
+                )?;
+                child.debug_recursive(f, style, false, &format!("{prefix}{}", connectors.space))?;
             }
         }
 
+        let hidden_len = children_len - shown_children.len();
+        if hidden_len > 0 {
+            let connector = Paint(
+                colors.connector,
+                format_args!("{prefix}{}", connectors.last),
+            );
+            write!(f, "\n{connector}... and {hidden_len} more")?;
+        }
+
         Ok(())
     }
 }
 
+#[expect(
+    clippy::missing_fields_in_debug,
+    reason = "the fields of `extras` are printed individually"
+)]
 impl Debug for Frame {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         if f.alternate() {
-            f.debug_struct("Frame")
+            let mut debug = f.debug_struct("Frame");
+            debug
                 .field("error", self.error())
                 .field("location", self.location)
                 .field("children", &self.children)
-                .finish()
+                .field("attachments", &self.attachments())
+                .field("multiplicity", &self.multiplicity())
+                .field("level", &self.level())
+                .field("code", &self.code())
+                .field("amendments", &self.extras().amendments)
+                .field("span", &self.span())
+                .field("help", &self.help());
+            #[cfg(feature = "timestamps")]
+            debug.field("timestamp", &self.timestamp);
+            #[cfg(feature = "threads")]
+            debug.field("thread", &self.thread);
+            #[cfg(feature = "tracing")]
+            debug.field("tracing_span", &self.tracing_span());
+            debug.finish()
         } else {
             self.debug(f)
         }
     }
 }
 
+/// Displays the error of this [`Frame`], followed by any [amendments].
+///
+/// Like [`Exn`], the alternate form (`{:#}`) displays this [`Frame`] and its children
+/// recursively, like [`Frame::debug_full`].
+///
+/// [amendments]: Exn::amend
 impl Display for Frame {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        Display::fmt(self.error(), f)
+        if f.alternate() {
+            return self.debug_full(f);
+        }
+        Display::fmt(self.error(), f)?;
+        match &self.extras().amendments {
+            Some(amendments) => write!(f, ": {amendments}"),
+            None => Ok(()),
+        }
     }
 }
 
@@ -271,8 +2093,227 @@ impl Error for Frame {
     }
 }
 
+/// Compares [`Frame`]s structurally by their messages (including [amendments]), locations,
+/// [multiplicities], [levels], [codes], [spans], [help], attachments, and children.
+///
+/// Since `dyn Error` implements neither [`PartialEq`] nor [`Hash`], errors are compared via their
+/// [`Display`] representations. Therefore, errors of different types with the same message are
+/// considered equal, and each comparison allocates [`String`]s for the messages of the compared
+/// [`Frame`]s.
+///
+/// Timestamps, threads, and `tracing` spans (see the `timestamps`, `threads`, and `tracing`
+/// features) are ignored, since [`Frame`]s that are created separately would otherwise rarely be
+/// equal.
+///
+/// [amendments]: Exn::amend
+/// [codes]: Frame::code
+/// [help]: Frame::help
+/// [levels]: Frame::level
+/// [multiplicities]: Frame::multiplicity
+/// [spans]: Frame::span
+impl PartialEq for Frame {
+    fn eq(&self, other: &Self) -> bool {
+        self.location == other.location
+            && self.extras() == other.extras()
+            && self.children == other.children
+            && self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Frame {}
+
+/// Hashes [`Frame`]s consistently with their [`PartialEq`] implementation.
+impl Hash for Frame {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_string().hash(state);
+        self.location.hash(state);
+        self.extras().hash(state);
+        self.children.hash(state);
+    }
+}
+
 impl<E: Error + Send + Sync + 'static> From<Exn<E>> for Frame {
     fn from(exn: Exn<E>) -> Self {
         *exn.frame
     }
 }
+
+/// Boxes the root [`Frame`] of the [`Exn`], whose [`Error::source`] follows the first child of each
+/// [`Frame`].
+impl<E: Error + Send + Sync + 'static> From<Exn<E>> for Box<dyn Error + Send + Sync + 'static> {
+    fn from(exn: Exn<E>) -> Self {
+        exn.frame
+    }
+}
+
+/// Wraps the root [`Frame`] of the [`Exn`] in an [`io::Error`] of kind [`ErrorKind::Other`].
+///
+/// The [`Display`] representation of the [`io::Error`] is that of the root [`Frame`], and its
+/// [`Error::source`] follows the first child of each [`Frame`]. The root [`Frame`] itself is
+/// available via [`io::Error::get_ref`].
+///
+/// [`io::Error`]: std::io::Error
+/// [`io::Error::get_ref`]: std::io::Error::get_ref
+/// [`ErrorKind::Other`]: std::io::ErrorKind::Other
+#[cfg(feature = "std")]
+impl<E: Error + Send + Sync + 'static> From<Exn<E>> for std::io::Error {
+    fn from(exn: Exn<E>) -> Self {
+        Self::other(*exn.frame)
+    }
+}
+
+/// Serializes this [`Frame`] and its children recursively.
+///
+/// Each [`Frame`] is serialized as a struct with the following fields:
+///
+/// - `message`: the [`String`] representation of the [`Frame`]'s error
+/// - `file`, `line`, and `column`: the [`Frame`]'s location
+/// - `level`: the [`Frame`]'s [`Level`] (e.g., `"WARN"`), or `null`
+/// - `code`: the [`Frame`]'s code, or `null`
+/// - `multiplicity`: the [`Frame`]'s multiplicity
+/// - `help`: the [`Frame`]'s help text, or `null`
+/// - `span`: the [`Frame`]'s [`Span`] (with `source`, `range`, and `label` fields), or `null`
+/// - `attachments`: a sequence of the [`Frame`]'s attachments as `[key, value]` pairs, in the order
+///   they were attached (keys may repeat)
+/// - `children`: a sequence of the [`Frame`]'s children
+///
+/// Errors are only serialized via [`Display`], so they need not implement
+/// [`Serialize`](serde::Serialize).
+#[cfg(feature = "serde")]
+impl serde::Serialize for Frame {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let location = self.source_location();
+        let mut state = serializer.serialize_struct("Frame", 11)?;
+        state.serialize_field("message", &self.to_string())?;
+        state.serialize_field("file", location.file)?;
+        state.serialize_field("line", &location.line)?;
+        state.serialize_field("column", &location.column)?;
+        state.serialize_field("level", &self.level())?;
+        state.serialize_field("code", &self.code())?;
+        state.serialize_field("multiplicity", &self.multiplicity())?;
+        state.serialize_field("help", &self.help())?;
+        state.serialize_field("span", &self.span())?;
+        state.serialize_field("attachments", self.attachments())?;
+        state.serialize_field("children", &self.children)?;
+        state.end()
+    }
+}
+
+/// Deserializable exception tree (e.g., received from another process)
+///
+/// The fields match those produced by [`Frame`]'s [`Serialize`](serde::Serialize)
+/// implementation. All but `message`, `file`, `line`, and `column` are optional. A [`FrameData`]
+/// can be converted into a [`Frame`] for rendering.
+///
+/// ```
+/// use exn::{Frame, FrameData, Level};
+///
+/// let data: FrameData = serde_json::from_str(
+///     r#"{"message": "failed", "file": "src/main.rs", "line": 1, "column": 2, "level": "WARN"}"#,
+/// )?;
+/// let frame = Frame::from(data);
+/// assert_eq!(frame.to_string(), "failed");
+/// assert_eq!(frame.level(), Some(Level::Warn));
+/// assert_eq!(format!("{frame:?}"), "[WARN] failed, at src/main.rs:1:2");
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize)]
+pub struct FrameData {
+    /// Message of the error
+    pub message: String,
+
+    /// File path of the location
+    pub file: String,
+
+    /// Line of the location
+    pub line: u32,
+
+    /// Column of the location
+    pub column: u32,
+
+    /// Severity of the frame
+    #[serde(default)]
+    pub level: Option<Level>,
+
+    /// Application-defined error code
+    #[serde(default)]
+    pub code: Option<i32>,
+
+    /// Number of identical sibling frames that this frame represents
+    #[serde(default = "FrameData::default_multiplicity")]
+    pub multiplicity: usize,
+
+    /// User-facing suggestion for resolving the error
+    #[serde(default)]
+    pub help: Option<String>,
+
+    /// Labeled range of source input that the frame refers to
+    #[serde(default)]
+    pub span: Option<Span>,
+
+    /// Key-value pairs that provide additional context
+    #[serde(default)]
+    pub attachments: Vec<(String, String)>,
+
+    /// Child frames
+    #[serde(default)]
+    pub children: Vec<FrameData>,
+}
+
+#[cfg(feature = "serde")]
+impl FrameData {
+    const fn default_multiplicity() -> usize {
+        1
+    }
+}
+
+/// Converts a [`FrameData`] and its children recursively into type-erased [`Frame`]s.
+///
+/// Since [`Location`]s can only be created by the compiler, [`Frame::location`] returns the
+/// callsite of this conversion, but the original location is rendered and serialized.
+#[cfg(feature = "serde")]
+impl From<FrameData> for Frame {
+    #[track_caller]
+    fn from(data: FrameData) -> Self {
+        fn convert(data: FrameData, location: &'static Location<'static>) -> Frame {
+            let children = data
+                .children
+                .into_iter()
+                .map(|child| convert(child, location))
+                .collect();
+            let mut frame =
+                Frame::from_parts(Box::new(SourceError(data.message)), location, children);
+            #[cfg(feature = "timestamps")]
+            {
+                frame.timestamp = None;
+            }
+            #[cfg(feature = "threads")]
+            {
+                frame.thread = None;
+            }
+            let extras = Extras {
+                attachments: (data.attachments.into_iter())
+                    .map(|(key, value)| (Cow::Owned(key), value))
+                    .collect(),
+                level: data.level,
+                code: data.code,
+                help: data.help,
+                span: data.span,
+                origin: Some(Origin {
+                    file: data.file,
+                    line: data.line,
+                    column: data.column,
+                }),
+                ..Extras::NONE
+            };
+            frame.extras = Some(Box::new(extras));
+            frame.set_multiplicity(data.multiplicity);
+            frame
+        }
+
+        convert(data, Location::caller())
+    }
+}
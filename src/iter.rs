@@ -12,7 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! [`Iterator`] extension trait
+//! [`Iterator`] extension traits and helpers
+
+use alloc::vec::Vec;
+use core::error::Error;
+
+use crate::Exn;
 
 /// Extension trait for [`Iterator`]s of [`Result`]s
 pub trait IteratorExt<T, E>: Iterator<Item = Result<T, E>> {
@@ -81,11 +86,252 @@ pub trait IteratorExt<T, E>: Iterator<Item = Result<T, E>> {
         self.by_ref()
             .collect::<Result<A, E>>()
             .map_err(|first_err| {
-                std::iter::once(first_err)
+                core::iter::once(first_err)
                     .chain(self.filter_map(Result::err))
                     .collect()
             })
     }
+
+    /// Transforms this [`Iterator`] of [`Result`]s into a [`Result`] of _collections_, retaining
+    /// at most `max_errors` errors.
+    ///
+    /// This is the same as [`collect_all`], except that errors beyond the first `max_errors` are
+    /// discarded, which bounds the size of the [`Err`] collection for very large inputs. Like
+    /// [`collect_all`], this method always consumes all items in `self`, even after `max_errors`
+    /// errors have been collected.
+    ///
+    /// # Errors
+    ///
+    /// If any item is [`Err`], this method will return [`Err`]. Note that if `max_errors` is `0`,
+    /// the [`Err`] collection is empty.
+    ///
+    /// [`collect_all`]: IteratorExt::collect_all
+    fn collect_all_capped<A, B>(mut self, max_errors: usize) -> Result<A, B>
+    where
+        Self: Sized,
+        A: FromIterator<T>,
+        B: FromIterator<E>,
+    {
+        self.by_ref()
+            .collect::<Result<A, E>>()
+            .map_err(|first_err| {
+                let errors = core::iter::once(first_err)
+                    .chain(self.by_ref().filter_map(Result::err))
+                    .take(max_errors)
+                    .collect();
+                self.for_each(drop);
+                errors
+            })
+    }
+
+    /// Partitions this [`Iterator`] of [`Result`]s into a collection of [`Ok`] values and a
+    /// collection of [`Err`] values.
+    ///
+    /// Unlike [`collect_all`], this method always returns _both_ collections, so the successes can
+    /// be processed even if some items failed. Like [`collect_all`], this method always consumes
+    /// all items in `self`, and the relative order of the items in each collection is preserved.
+    ///
+    /// ```
+    /// use exn::IteratorExt;
+    ///
+    /// let (numbers, errors): (Vec<i32>, Vec<_>) = ["1", "two", "3"]
+    ///     .into_iter()
+    ///     .map(str::parse::<i32>)
+    ///     .partition_all();
+    /// assert_eq!(numbers, [1, 3]);
+    /// assert_eq!(errors.len(), 1);
+    /// ```
+    ///
+    /// [`collect_all`]: IteratorExt::collect_all
+    fn partition_all<A, B>(self) -> (A, B)
+    where
+        Self: Sized,
+        A: Default + Extend<T>,
+        B: Default + Extend<E>,
+    {
+        let mut oks = A::default();
+        let mut errs = B::default();
+        for result in self {
+            match result {
+                Ok(t) => oks.extend(Some(t)),
+                Err(e) => errs.extend(Some(e)),
+            }
+        }
+        (oks, errs)
+    }
 }
 
 impl<I, T, E> IteratorExt<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+/// Extension trait for [`Iterator`]s of keyed [`Result`]s
+pub trait KeyedIteratorExt<K, T, E>: Iterator<Item = (K, Result<T, E>)> {
+    /// Transforms this [`Iterator`] of keyed [`Result`]s into a [`Result`] of keyed _collections_.
+    ///
+    /// This is the same as [`IteratorExt::collect_all`], except that each value and error is
+    /// paired with its key, so the [`Err`] collection identifies which items failed. Like
+    /// [`IteratorExt::collect_all`], this method always consumes all items in `self`.
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    ///
+    /// use exn::KeyedIteratorExt;
+    ///
+    /// let errors = [("a", "1"), ("b", "x"), ("c", "y")]
+    ///     .into_iter()
+    ///     .map(|(key, value)| (key, value.parse::<i32>()))
+    ///     .collect_all_keyed::<Vec<_>, BTreeMap<_, _>>()
+    ///     .unwrap_err();
+    /// assert_eq!(errors.keys().copied().collect::<Vec<_>>(), ["b", "c"]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If any item is [`Err`], this method will return [`Err`].
+    fn collect_all_keyed<A, B>(self) -> Result<A, B>
+    where
+        Self: Sized,
+        A: FromIterator<(K, T)>,
+        B: FromIterator<(K, E)>,
+    {
+        self.map(|(key, result)| match result {
+            Ok(t) => Ok((key, t)),
+            Err(e) => Err((key, e)),
+        })
+        .collect_all()
+    }
+}
+
+impl<I, K, T, E> KeyedIteratorExt<K, T, E> for I where I: Iterator<Item = (K, Result<T, E>)> {}
+
+/// Extension trait for applying fallible operations to all items of an [`Iterator`]
+pub trait ForEachAllExt: Iterator {
+    /// Calls `f` on each item of this [`Iterator`], collecting all errors.
+    ///
+    /// This is like [`Iterator::try_for_each`], except that it is _not_ short-circuiting; `f` is
+    /// always called on every item, even after it has failed. The errors are collected into `B`
+    /// without materializing the [`Ok`] values (see [`IteratorExt::collect_all`]).
+    ///
+    /// ```
+    /// use exn::ForEachAllExt;
+    ///
+    /// let mut sum = 0;
+    /// let errors: Vec<_> = ["1", "two", "3"]
+    ///     .into_iter()
+    ///     .for_each_all(|s| s.parse::<i32>().map(|n| sum += n))
+    ///     .unwrap_err();
+    /// assert_eq!(sum, 4);
+    /// assert_eq!(errors.len(), 1);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If `f` returns [`Err`] for any item, this method will return [`Err`].
+    fn for_each_all<F, E, B>(self, f: F) -> Result<(), B>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<(), E>,
+        B: FromIterator<E>,
+    {
+        self.map(f).collect_all()
+    }
+}
+
+impl<I: Iterator> ForEachAllExt for I {}
+
+/// Collects the [`Ok`] values of `results`, or raises all of the [`Exn`]s as children of a new
+/// [`Exn`] whose context is provided by `parent`.
+///
+/// This is shorthand for [`IteratorExt::collect_all`] followed by [`Exn::raise_all`]:
+///
+/// ```no_run
+/// use std::io::Error;
+///
+/// use exn::{Result, ResultExt};
+///
+/// fn main() -> Result<(), Error> {
+///     let files = exn::combine(
+///         ["a/b", "c/d", "e/f", "g/h", "i/j"].into_iter().map(|path| {
+///             std::fs::File::open(path)
+///                 .or_raise(|| Error::other(format!("failed to open {path}")))
+///         }),
+///         || Error::other("example"),
+///     )?;
+///
+///     // Do stuff with files…
+/// #   drop(files);
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// # Errors
+///
+/// If any item of `results` is [`Err`], this function returns [`Err`] with every [`Exn`] as a
+/// child, in order.
+#[track_caller]
+pub fn combine<I, T, E, F>(results: I, parent: F) -> crate::Result<Vec<T>, E>
+where
+    I: IntoIterator<Item = crate::Result<T, E>>,
+    E: Error + Send + Sync + 'static,
+    F: FnOnce() -> E,
+{
+    // Note: We can't use `Result::map_err` since `#[track_caller]` on closures is currently
+    // unstable.
+    match results.into_iter().collect_all::<_, Vec<_>>() {
+        Ok(values) => Ok(values),
+        Err(children) => Err(Exn::raise_all(children, parent())),
+    }
+}
+
+/// Extension trait for [`ParallelIterator`]s of [`Result`]s
+///
+/// [`ParallelIterator`]: rayon::iter::ParallelIterator
+#[cfg(feature = "rayon")]
+pub trait ParallelIteratorExt<T, E>: rayon::iter::ParallelIterator<Item = Result<T, E>>
+where
+    T: Send,
+    E: Send,
+{
+    /// Transforms this [`ParallelIterator`] of [`Result`]s into a [`Result`] of _collections_.
+    ///
+    /// This is the parallel equivalent of [`IteratorExt::collect_all`]; it will always consume
+    /// all items in `self`.
+    ///
+    /// Since items are processed in parallel, the order of the collected errors is only
+    /// deterministic if both `self` and `B` preserve order (e.g., an [`IndexedParallelIterator`]
+    /// collected into a [`Vec`]).
+    ///
+    /// # Errors
+    ///
+    /// If any item is [`Err`], this method will return [`Err`].
+    ///
+    /// [`ParallelIterator`]: rayon::iter::ParallelIterator
+    /// [`IndexedParallelIterator`]: rayon::iter::IndexedParallelIterator
+    fn collect_all<A, B>(self) -> Result<A, B>
+    where
+        Self: Sized,
+        A: rayon::iter::FromParallelIterator<T>,
+        B: rayon::iter::FromParallelIterator<E>,
+    {
+        use rayon::iter::{Either, IntoParallelIterator, ParallelIterator};
+
+        let (oks, errs): (Vec<T>, Vec<E>) = self.partition_map(|result| match result {
+            Ok(t) => Either::Left(t),
+            Err(e) => Either::Right(e),
+        });
+        if errs.is_empty() {
+            Ok(oks.into_par_iter().collect())
+        } else {
+            Err(errs.into_par_iter().collect())
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<I, T, E> ParallelIteratorExt<T, E> for I
+where
+    I: rayon::iter::ParallelIterator<Item = Result<T, E>>,
+    T: Send,
+    E: Send,
+{
+}
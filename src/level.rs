@@ -0,0 +1,65 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt::{self, Display, Formatter};
+
+/// Severity of a [`Frame`]
+///
+/// The variants mirror those of [`log::Level`] (in increasing order of severity) without depending
+/// on the [`log`] crate. See [`Exn::with_level`]. With the `serde` feature, a [`Level`] is
+/// serialized as its upper-case name (see [`Level::as_str`]).
+///
+/// [`Exn::with_level`]: crate::Exn::with_level
+/// [`Frame`]: crate::Frame
+/// [`log`]: https://docs.rs/log
+/// [`log::Level`]: https://docs.rs/log/latest/log/enum.Level.html
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
+pub enum Level {
+    /// Very low priority, often extremely verbose, information
+    Trace,
+
+    /// Lower priority information
+    Debug,
+
+    /// Useful information
+    Info,
+
+    /// Hazardous situations
+    Warn,
+
+    /// Very serious errors
+    Error,
+}
+
+impl Level {
+    /// Returns the upper-case name of this [`Level`] (e.g., `"WARN"`).
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Trace => "TRACE",
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
@@ -76,26 +76,88 @@
 //! └─ logic error: 0 == 1, at src/lib.rs:41:5
 //! ```
 //!
-//! [`Error`]: std::error::Error
+//! # Features
+//!
+//! - `backtrace`: Captures a [`Backtrace`] when an [`Exn`] is created, which is accessible via
+//!   `Exn::backtrace`.
+//! - `json`: Enables `repr::Json` for rendering exception trees as JSON. Implies `serde`.
+//! - `miette`: Enables `repr::Miette` for interoperation with the `miette` crate.
+//! - `rayon`: Enables `ParallelIteratorExt` for `rayon` parallel iterators.
+//! - `serde`: Implements `serde::Serialize` for [`Frame`] and enables `FrameData` for deserializing
+//!   exception trees.
+//! - `std` (default): Links the standard library. Without it, `exn` is `no_std` and only requires
+//!   `alloc`. All other features except `serde` imply `std`.
+//! - `threads`: Records the thread on which each [`Frame`] is created, which is accessible via
+//!   `Frame::thread_name` and `Frame::thread_id` and can be printed via `DebugOptions::threads`.
+//! - `timestamps`: Records the time at which each [`Frame`] is created, which is accessible via
+//!   `Frame::timestamp` and can be printed via `DebugOptions::timestamps`.
+//! - `tokio`: Enables `from_join_error` for converting `tokio` task failures into [`Exn`]s.
+//! - `tracing`: Enables `repr::Tracing` for emitting exception trees as `tracing` events, and
+//!   records the current `tracing` span when an [`Exn`] is created, which is accessible via
+//!   `Frame::tracing_span`. While a span is entered, this costs a heap allocation for each new
+//!   [`Exn`], but not for each [`Frame`] raised on top of it.
+//!
+//! [`Backtrace`]: std::backtrace::Backtrace
+//! [`Error`]: core::error::Error
 
+#![no_std]
 #![deny(missing_docs)]
 #![warn(clippy::pedantic, clippy::map_err_ignore)]
 
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 pub mod repr;
 
+mod boxed;
+mod color;
+mod debug;
 mod error;
 mod exn;
 mod iter;
+mod level;
 mod macros;
+mod message;
 mod option;
+#[cfg(feature = "std")]
+mod panic;
 mod result;
+mod span;
+#[cfg(feature = "tokio")]
+mod task;
+mod visit;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::{format, vec::Vec};
+}
 
+#[cfg(feature = "serde")]
+#[doc(inline)]
+pub use self::exn::FrameData;
+#[cfg(feature = "rayon")]
+#[doc(inline)]
+pub use self::iter::ParallelIteratorExt;
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use self::panic::{PanicError, catch};
+#[cfg(feature = "tokio")]
+#[doc(inline)]
+pub use self::task::{TaskError, from_join_error};
 #[doc(inline)]
 pub use self::{
+    boxed::BoxError,
+    color::ColorSpec,
+    debug::{Connectors, DebugOptions, LocationFormat},
     error::ErrorExt,
-    exn::{Exn, Frame},
-    iter::IteratorExt,
-    option::OptionExt,
+    exn::{Exn, Frame, LOG_LINE_MAX_LEN},
+    iter::{ForEachAllExt, IteratorExt, KeyedIteratorExt, combine},
+    level::Level,
+    message::Message,
+    option::{OptionExnExt, OptionExt},
     repr::{ExnAny, Repr},
-    result::{Ok, Result, ResultExt},
+    result::{Ok, Result, ResultExnExt, ResultExt},
+    span::Span,
+    visit::FrameVisitor,
 };
@@ -16,7 +16,9 @@
 
 /// Creates an [`Exn`] and returns it as an [`Err`].
 ///
-/// # Example
+/// Given a format string and arguments (like [`format!`]), the error is a [`Message`].
+///
+/// # Examples
 ///
 /// ```no_run
 /// use std::io::Error;
@@ -28,16 +30,68 @@
 /// }
 /// ```
 ///
+/// ```no_run
+/// use exn::{Message, Result, bail};
+///
+/// fn main() -> Result<(), Message> {
+///     let path = "config.toml";
+///     bail!("failed to read {path}");
+/// }
+/// ```
+///
 /// [`Exn`]: crate::Exn
+/// [`format!`]: alloc::format
+/// [`Message`]: crate::Message
 #[macro_export]
 macro_rules! bail {
-    ($err:expr) => {{
+    ($fmt:literal $(, $arg:expr)* $(,)?) => {{
+        return ::core::result::Result::Err($crate::Exn::from($crate::Message::new(
+            $crate::__private::format!($fmt $(, $arg)*),
+        )));
+    }};
+    ($err:expr $(,)?) => {{
         return ::core::result::Result::Err($crate::Exn::from($err));
     }};
 }
 
+/// Creates an [`Exn`] with the given `$children` and returns it as an [`Err`].
+///
+/// This is shorthand for returning [`Exn::raise_all`], so `$children` may be any iterable of
+/// [`Exn`]s of the same type. Like [`bail!`], this also accepts a format string and arguments in
+/// place of an error.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::io::Error;
+///
+/// use exn::{ErrorExt, Result, bail_all};
+///
+/// fn main() -> Result<(), Error> {
+///     let children = ["a", "b"].map(|name| Error::other(name).raise());
+///     bail_all!(children, Error::other("bailed"));
+/// }
+/// ```
+///
+/// [`Exn`]: crate::Exn
+/// [`Exn::raise_all`]: crate::Exn::raise_all
+#[macro_export]
+macro_rules! bail_all {
+    ($children:expr, $fmt:literal $(, $arg:expr)* $(,)?) => {{
+        return ::core::result::Result::Err($crate::Exn::raise_all(
+            $children,
+            $crate::Message::new($crate::__private::format!($fmt $(, $arg)*)),
+        ));
+    }};
+    ($children:expr, $err:expr $(,)?) => {{
+        return ::core::result::Result::Err($crate::Exn::raise_all($children, $err));
+    }};
+}
+
 /// Creates an [`Exn`] and returns it as an [`Err`] if `$cond` is false.
 ///
+/// Like [`bail!`], this also accepts a format string and arguments in place of an error.
+///
 /// # Example
 ///
 /// ```no_run
@@ -59,9 +113,61 @@ macro_rules! bail {
 /// [`Exn`]: crate::Exn
 #[macro_export]
 macro_rules! ensure {
+    ($cond:expr, $fmt:literal $(, $arg:expr)* $(,)?) => {{
+        if !bool::from($cond) {
+            $crate::bail!($fmt $(, $arg)*)
+        }
+    }};
     ($cond:expr, $err:expr $(,)?) => {{
         if !bool::from($cond) {
             $crate::bail!($err)
         }
     }};
 }
+
+/// Evaluates every condition and, if any are false, returns an [`Exn`] with a child for each.
+///
+/// Each pair in the list consists of a condition and an error, which is only evaluated if its
+/// condition is false. Unlike consecutive calls to [`ensure!`], which return at the first failure,
+/// every condition is evaluated, and the errors of all failed conditions become the children of
+/// the returned [`Exn`] (see [`bail_all!`]). The errors of the conditions must all be of the same
+/// type. Like [`bail!`], this also accepts a format string and arguments in place of the parent
+/// error.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Error;
+///
+/// use exn::{Result, ensure_all};
+///
+/// fn validate(host: &str, port: u16) -> Result<(), Error> {
+///     ensure_all!(
+///         [
+///             (!host.is_empty(), Error::other("missing host")),
+///             (port != 0, Error::other("invalid port")),
+///         ],
+///         Error::other("invalid config"),
+///     );
+///     Ok(())
+/// }
+///
+/// let exn = validate("", 0).unwrap_err();
+/// assert_eq!(exn.frame().children().len(), 2);
+/// ```
+///
+/// [`Exn`]: crate::Exn
+#[macro_export]
+macro_rules! ensure_all {
+    ([$(($cond:expr, $child:expr)),* $(,)?], $($parent:tt)+) => {{
+        let mut children = $crate::__private::Vec::new();
+        $(
+            if !bool::from($cond) {
+                children.push($crate::Exn::new($child));
+            }
+        )*
+        if !children.is_empty() {
+            $crate::bail_all!(children, $($parent)+)
+        }
+    }};
+}
@@ -0,0 +1,48 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::{boxed::Box, string::ToString};
+use core::{
+    error::Error,
+    fmt::{self, Debug, Display, Formatter},
+};
+
+/// [`Error`] that consists solely of a displayable message
+///
+/// This is useful for simple context that doesn't warrant a dedicated error type (see
+/// [`ResultExt::context`]).
+///
+/// [`ResultExt::context`]: crate::ResultExt::context
+pub struct Message(Box<dyn Display + Send + Sync + 'static>);
+
+impl Message {
+    /// Creates a new [`Message`] from anything that implements [`Display`].
+    pub fn new<C: Display + Send + Sync + 'static>(message: C) -> Self {
+        Self(Box::new(message))
+    }
+}
+
+impl Debug for Message {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(&self.0.to_string(), f)
+    }
+}
+
+impl Display for Message {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Error for Message {}
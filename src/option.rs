@@ -14,7 +14,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::error::Error;
+use core::error::Error;
 
 use crate::{Exn, Result};
 
@@ -30,6 +30,21 @@ pub trait OptionExt {
         A: Into<B>,
         B: Error + Send + Sync + 'static,
         F: FnOnce() -> A;
+
+    /// Raises `err` as a new [`Exn`] if `self` is [`None`].
+    ///
+    /// Unlike [`OptionExt::ok_or_raise`], `err` is evaluated eagerly, which avoids a closure when
+    /// the error is cheap to construct.
+    #[expect(clippy::missing_errors_doc, reason = "similar to `Option::ok_or`")]
+    #[track_caller]
+    fn ok_or_raise_from<A, B>(self, err: A) -> Result<Self::Some, B>
+    where
+        Self: Sized,
+        A: Into<B>,
+        B: Error + Send + Sync + 'static,
+    {
+        self.ok_or_raise(|| err)
+    }
 }
 
 impl<T> OptionExt for Option<T> {
@@ -51,3 +66,55 @@ impl<T> OptionExt for Option<T> {
         }
     }
 }
+
+/// Raising optional [`Exn`]s
+pub trait OptionExnExt {
+    /// Raises the contained [`Exn`] as the child of a new [`Exn`] whose context is provided by
+    /// `err`, or returns [`Ok`] if `self` is [`None`].
+    ///
+    /// This is useful for APIs that report failure as an optional error:
+    ///
+    /// ```
+    /// use std::io::Error;
+    ///
+    /// use exn::{ErrorExt, Exn, OptionExnExt, Result};
+    ///
+    /// fn validate(input: &str) -> Option<Exn<Error>> {
+    ///     input
+    ///         .is_empty()
+    ///         .then(|| Error::other("empty input").raise())
+    /// }
+    ///
+    /// fn parse(input: &str) -> Result<(), Error> {
+    ///     validate(input).transpose_raise(|| Error::other("invalid"))
+    /// }
+    ///
+    /// assert!(parse("ok").is_ok());
+    /// let exn = parse("").unwrap_err();
+    /// assert_eq!(exn.to_string(), "invalid");
+    /// assert_eq!(exn.frame().children()[0].to_string(), "empty input");
+    /// ```
+    #[expect(clippy::missing_errors_doc, reason = "similar to `Result::map_err`")]
+    fn transpose_raise<A, B, F>(self, err: F) -> Result<(), B>
+    where
+        A: Into<B>,
+        B: Error + Send + Sync + 'static,
+        F: FnOnce() -> A;
+}
+
+impl<E: Error + Send + Sync + 'static> OptionExnExt for Option<Exn<E>> {
+    #[track_caller]
+    fn transpose_raise<A, B, F>(self, err: F) -> Result<(), B>
+    where
+        A: Into<B>,
+        B: Error + Send + Sync + 'static,
+        F: FnOnce() -> A,
+    {
+        // Note: We can't use `Option::map` since `#[track_caller]` on closures is currently
+        // unstable.
+        match self {
+            Some(exn) => Err(exn.raise(err().into())),
+            None => Ok(()),
+        }
+    }
+}
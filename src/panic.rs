@@ -0,0 +1,148 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+};
+use core::{
+    any::Any,
+    cell::{Cell, RefCell},
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+use std::{
+    panic::{self, UnwindSafe},
+    sync::Once,
+    thread, thread_local,
+};
+
+use crate::{Exn, Result};
+
+thread_local! {
+    /// Number of nested calls to [`catch`] on this thread
+    static CATCHING: Cell<usize> = const { Cell::new(0) };
+
+    /// Location of the most recent panic caught by [`catch`] on this thread
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Calls `f`, converting a panic into an [`Exn`].
+///
+/// The [`PanicError`] of the [`Exn`] holds the panic's message and location, and the location of
+/// the [`Exn`] is the callsite of [`catch`]. Like [`std::panic::catch_unwind`], this only catches
+/// unwinding panics.
+///
+/// The first call to [`catch`] installs a [panic hook] that records panic locations before
+/// delegating to the previously installed hook. If the hook is replaced later, panic locations are
+/// unavailable. Since the hook cannot be replaced while the thread is panicking, it is not
+/// installed by calls to [`catch`] during unwinding (e.g., from a [`Drop`] implementation), so
+/// such calls do not record panic locations unless the hook was already installed.
+///
+/// ```
+/// let result = exn::catch(|| panic!("oh no"));
+/// assert_eq!(result.unwrap_err().to_string(), "oh no");
+/// ```
+///
+/// # Errors
+///
+/// If `f` panics, this returns [`Err`].
+///
+/// [panic hook]: std::panic::set_hook
+#[track_caller]
+pub fn catch<F, T>(f: F) -> Result<T, PanicError>
+where
+    F: FnOnce() -> T + UnwindSafe,
+{
+    install_hook();
+
+    // Discard the location of any panic that was caught by something else (e.g., a nested
+    // `catch_unwind`), which would otherwise be attributed to a panic without one.
+    LOCATION.with(RefCell::take);
+    CATCHING.with(|catching| catching.set(catching.get() + 1));
+    let result = panic::catch_unwind(f);
+    CATCHING.with(|catching| catching.set(catching.get() - 1));
+
+    match result {
+        Ok(t) => {
+            LOCATION.with(RefCell::take);
+            Ok(t)
+        }
+        Err(payload) => {
+            let location = LOCATION.with(RefCell::take);
+            Err(Exn::new(PanicError::new(&*payload, location)))
+        }
+    }
+}
+
+fn install_hook() {
+    static HOOK: Once = Once::new();
+
+    // `panic::take_hook` panics if the thread is panicking, which would abort the process.
+    if thread::panicking() {
+        return;
+    }
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(Cell::get) > 0 {
+                let location = info.location().map(ToString::to_string);
+                LOCATION.with(|cell| *cell.borrow_mut() = location);
+            }
+            previous(info);
+        }));
+    });
+}
+
+/// [`Error`] that represents a panic caught by [`catch`]
+///
+/// The [`Display`] representation is the panic's message.
+#[derive(Debug)]
+pub struct PanicError {
+    message: Option<String>,
+    location: Option<String>,
+}
+
+impl PanicError {
+    /// Creates a [`PanicError`] from a panic's `payload` and optional `location`.
+    pub(crate) fn new(payload: &(dyn Any + Send), location: Option<String>) -> Self {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            Some((*message).to_string())
+        } else {
+            payload.downcast_ref::<String>().cloned()
+        };
+        Self { message, location }
+    }
+
+    /// Returns the panic's message, if its payload was a string.
+    #[must_use]
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Returns the location of the panic (as `file:line:column`), if available.
+    #[must_use]
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+}
+
+impl Display for PanicError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // This matches the standard library's message for non-string payloads.
+        f.write_str(self.message().unwrap_or("Box<dyn Any>"))
+    }
+}
+
+impl Error for PanicError {}
@@ -15,17 +15,53 @@
 //! [`Error`] representations for [`Exn`] via type-erasure
 
 mod anyhow;
+mod caused_by;
+mod compact;
+mod dot;
+mod html;
+#[cfg(feature = "json")]
+mod json;
 mod list;
+mod markdown;
+#[cfg(feature = "miette")]
+mod miette;
+mod minimal;
+mod plain;
+mod syslog;
+#[cfg(feature = "tracing")]
+mod tracing;
 mod tree;
 
-use std::{
+use alloc::boxed::Box;
+use core::{
     error::Error,
     fmt::{Debug, Display, Formatter, Result},
     marker::PhantomData,
 };
 
+#[cfg(feature = "json")]
 #[doc(inline)]
-pub use self::{anyhow::Anyhow, list::List, tree::Tree};
+pub use self::json::Json;
+#[cfg(feature = "miette")]
+#[doc(inline)]
+pub use self::miette::Miette;
+#[cfg(feature = "tracing")]
+#[doc(inline)]
+pub use self::tracing::Tracing;
+#[doc(inline)]
+pub use self::{
+    anyhow::{Anyhow, Eyre},
+    caused_by::CausedBy,
+    compact::Compact,
+    dot::Dot,
+    html::Html,
+    list::List,
+    markdown::Markdown,
+    minimal::Minimal,
+    plain::Plain,
+    syslog::Syslog,
+    tree::Tree,
+};
 use crate::Exn;
 
 /// [`ExnAny`] representation marker trait
@@ -69,6 +105,21 @@ pub trait Repr {
 /// Any type that implements [`Repr`] may be provided as a type parameter to [`ExnAny`] ([`Tree`]
 /// is the default).
 ///
+/// # Alternate display
+///
+/// [`ExnAny`] forwards formatting flags to its [`Repr`], so the alternate [`Display`] form
+/// (`{:#}`) prints everything that the [`Repr`] represents, like it does for [`Exn`]:
+///
+/// - [`Tree`], [`List`], [`Html`], and the feature-gated `Miette` and `Tracing`: `{}` displays the
+///   top-level message, and `{:#}` displays the entire exception tree.
+/// - [`CausedBy`]: `{}` displays the top-level message, and `{:#}` joins the messages of the chain
+///   with `: `.
+/// - [`Compact`], [`Dot`], [`Markdown`], [`Plain`], [`Syslog`], and the feature-gated `Json`: `{}`
+///   already renders the entire exception tree, so `{:#}` is the same.
+/// - [`Minimal`]: Both forms display only the message of the root cause.
+/// - [`Anyhow`] and [`Eyre`]: Both forms display the [`Debug`] representation of the
+///   sub-representation.
+///
 /// [`anyhow::Error`]: ::anyhow::Error
 pub struct ExnAny<T: Repr = Tree> {
     error: Box<dyn Error + Send + Sync + 'static>,
@@ -16,7 +16,7 @@
 //!
 //! [`ExnAny`]: crate::ExnAny
 
-use std::{
+use core::{
     error::Error,
     fmt::{Debug, Display, Formatter, Result},
     marker::PhantomData,
@@ -70,6 +70,37 @@ use crate::Exn;
 /// [`List`]: crate::repr::List
 pub struct Anyhow<T: Repr = Tree>(PhantomData<T>);
 
+/// [`ExnAny`] representation for interoperation with the [`eyre`] crate
+///
+/// [`eyre::Report`] renders its source chain like [`anyhow::Error`], so this is an alias of
+/// [`Anyhow`]; see its documentation for details, including sub-representations.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::io::Error;
+///
+/// use exn::{ErrorExt, Exn, ExnAny, repr::Eyre};
+/// use eyre::WrapErr;
+///
+/// fn foo() -> Result<(), ExnAny<Eyre>> {
+///     let child = Error::other("child").raise();
+///     let parent = child.raise(Error::other("parent"));
+///     Err(parent.into())
+/// }
+///
+/// fn main() -> eyre::Result<()> {
+///     foo().wrap_err("context")?;
+///     Ok(())
+/// }
+/// ```
+///
+/// [`ExnAny`]: crate::ExnAny
+/// [`anyhow::Error`]: https://docs.rs/anyhow/latest/anyhow/struct.Error.html
+/// [`eyre`]: https://docs.rs/eyre
+/// [`eyre::Report`]: https://docs.rs/eyre/latest/eyre/struct.Report.html
+pub type Eyre<T = Tree> = Anyhow<T>;
+
 impl<R: Repr> Repr for Anyhow<R> {
     type Impl<T: Error + Send + Sync + 'static> = AnyhowExn<R::Impl<T>>;
 }
@@ -85,8 +116,9 @@ impl<T: Error + Send + Sync + 'static> Debug for AnyhowExn<T> {
 impl<T: Error + Send + Sync + 'static> Display for AnyhowExn<T> {
     fn fmt(&self, f: &mut Formatter) -> Result {
         // This is _not_ a typo! We want to use the underlying `Debug` representation in the
-        // rendering of the source chain.
-        Debug::fmt(&self.0, f)
+        // rendering of the source chain. The formatting flags are dropped so that the alternate
+        // form (e.g., `anyhow`'s `{:#}`) does not pretty-print the `Debug` representation.
+        write!(f, "{:?}", self.0)
     }
 }
 
@@ -0,0 +1,108 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result},
+};
+
+use crate::{Exn, Frame, Repr};
+
+/// [`ExnAny`] representation that renders the exception tree like [`anyhow::Error`]
+///
+/// Like [`List`], this coerces the exception tree into a chain by following the first child of
+/// each [`Frame`]. The [`Display`] representation is the message of the top-level [`Frame`] alone,
+/// and the [`Debug`] representation follows it with a `Caused by:` block listing the rest of the
+/// chain, numbered and indented as in [`anyhow`]'s default format. The number is omitted when there
+/// is only one cause. Also like [`anyhow`], the alternate [`Display`] representation (`{:#}`) joins
+/// the messages of the entire chain with `: `.
+///
+/// ```
+/// use std::io;
+///
+/// use exn::{ErrorExt, ExnAny, repr::CausedBy};
+///
+/// let child = io::Error::other("child").raise();
+/// let exn: ExnAny<CausedBy> = child.raise(io::Error::other("parent")).into();
+/// assert_eq!(exn.to_string(), "parent");
+/// println!("{exn:?}");
+/// ```
+///
+/// This prints something similar to the following (locations elided for clarity and brevity):
+///
+/// ```text
+/// parent, at …
+///
+/// Caused by:
+///     child, at …
+/// ```
+///
+/// [`ExnAny`]: crate::ExnAny
+/// [`List`]: crate::repr::List
+/// [`anyhow`]: https://docs.rs/anyhow
+/// [`anyhow::Error`]: https://docs.rs/anyhow/latest/anyhow/struct.Error.html
+pub struct CausedBy;
+
+impl Repr for CausedBy {
+    type Impl<T: Error + Send + Sync + 'static> = CausedByExn;
+}
+
+pub struct CausedByExn(Frame);
+
+impl Debug for CausedByExn {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        self.0.debug(f)?;
+        let mut causes = self.0.spine().skip(1).peekable();
+        if causes.peek().is_none() {
+            return Ok(());
+        }
+        f.write_str("\n\nCaused by:")?;
+        let numbered = self.0.spine().nth(2).is_some();
+        for (i, frame) in causes.enumerate() {
+            f.write_str("\n    ")?;
+            if numbered {
+                write!(f, "{i}: ")?;
+            }
+            frame.debug(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for CausedByExn {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if !f.alternate() {
+            return Display::fmt(&self.0, f);
+        }
+        for (i, frame) in self.0.spine().enumerate() {
+            if i > 0 {
+                f.write_str(": ")?;
+            }
+            write!(f, "{frame}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for CausedByExn {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.0.source()
+    }
+}
+
+impl<T: Error + Send + Sync + 'static> From<Exn<T>> for CausedByExn {
+    fn from(exn: Exn<T>) -> Self {
+        Self(exn.into())
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result},
+};
+
+use crate::{Exn, Frame, Repr};
+
+/// [`ExnAny`] representation that renders the exception tree on a single line
+///
+/// Like [`List`], this coerces the exception tree into a chain by following the first child of
+/// each [`Frame`]. Both the [`Debug`] and [`Display`] representations join the messages of the
+/// chain with [`Compact::SEPARATOR`], omitting locations entirely.
+///
+/// ```
+/// use std::io;
+///
+/// use exn::{ErrorExt, ExnAny, repr::Compact};
+///
+/// let child = io::Error::other("child").raise();
+/// let exn: ExnAny<Compact> = child.raise(io::Error::other("parent")).into();
+/// assert_eq!(exn.to_string(), "parent: child");
+/// ```
+///
+/// [`ExnAny`]: crate::ExnAny
+/// [`List`]: crate::repr::List
+pub struct Compact;
+
+impl Compact {
+    /// Separator between the messages of consecutive [`Frame`]s
+    pub const SEPARATOR: &'static str = ": ";
+}
+
+impl Repr for Compact {
+    type Impl<T: Error + Send + Sync + 'static> = CompactExn;
+}
+
+pub struct CompactExn(Frame);
+
+impl Debug for CompactExn {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for CompactExn {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        for (i, frame) in self.0.spine().enumerate() {
+            if i > 0 {
+                f.write_str(Compact::SEPARATOR)?;
+            }
+            write!(f, "{frame}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for CompactExn {}
+
+impl<T: Error + Send + Sync + 'static> From<Exn<T>> for CompactExn {
+    fn from(exn: Exn<T>) -> Self {
+        Self(exn.into())
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::vec::Vec;
+use core::{
+    error::Error,
+    fmt::{self, Arguments, Debug, Display, Formatter, Write},
+};
+
+use crate::{
+    Exn, Frame, Repr,
+    visit::{self, TreeWriter},
+};
+
+/// [`ExnAny`] representation that renders the exception tree as a [Graphviz] DOT `digraph`
+///
+/// Each [`Frame`] is a node labeled with its message and location, and each edge points from a
+/// parent to one of its children. Nodes are identified by their index in depth-first preorder (see
+/// [`Frame::iter`]), so identifiers are stable for a given tree. Both the [`Debug`] and
+/// [`Display`] representations are the same.
+///
+/// ```
+/// use std::io;
+///
+/// use exn::{ErrorExt, ExnAny, repr::Dot};
+///
+/// let child = io::Error::other("child").raise();
+/// let exn: ExnAny<Dot> = child.raise(io::Error::other("parent")).into();
+/// assert!(exn.to_string().contains("n0 -> n1;"));
+/// ```
+///
+/// [`ExnAny`]: crate::ExnAny
+/// [Graphviz]: https://graphviz.org/
+pub struct Dot;
+
+impl Repr for Dot {
+    type Impl<T: Error + Send + Sync + 'static> = DotExn;
+}
+
+pub struct DotExn(Frame);
+
+/// Writes the node for each [`Frame`] and the edge from its parent
+#[derive(Default)]
+struct NodeWriter {
+    /// Identifiers of the ancestors of the next [`Frame`]
+    parents: Vec<usize>,
+
+    /// Next unused node identifier
+    next_id: usize,
+}
+
+impl TreeWriter for NodeWriter {
+    fn enter(&mut self, f: &mut Formatter, frame: &Frame, _: usize) -> fmt::Result {
+        let id = self.next_id;
+        self.next_id += 1;
+        if let Some(parent) = self.parents.last() {
+            writeln!(f, "    n{parent} -> n{id};")?;
+        }
+        self.parents.push(id);
+
+        let location = frame.source_location();
+        write!(f, "    n{id} [label=\"")?;
+        write_escaped(f, format_args!("{frame}\nat {location}"))?;
+        f.write_str("\"];\n")
+    }
+
+    fn leave(&mut self, _: &mut Formatter, _: &Frame, _: usize) -> fmt::Result {
+        self.parents.pop();
+        Ok(())
+    }
+}
+
+/// Writes `args` as the contents of a DOT string literal.
+fn write_escaped(f: &mut Formatter, args: Arguments) -> fmt::Result {
+    struct Escaper<'a, 'b>(&'a mut Formatter<'b>);
+
+    impl Write for Escaper<'_, '_> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            for c in s.chars() {
+                match c {
+                    '"' => self.0.write_str("\\\"")?,
+                    '\\' => self.0.write_str("\\\\")?,
+                    '\n' => self.0.write_str("\\n")?,
+                    c => self.0.write_char(c)?,
+                }
+            }
+            Ok(())
+        }
+    }
+
+    Escaper(f).write_fmt(args)
+}
+
+impl Debug for DotExn {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for DotExn {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("digraph {\n")?;
+        visit::write_tree(f, &self.0, NodeWriter::default())?;
+        f.write_str("}")
+    }
+}
+
+impl Error for DotExn {}
+
+impl<T: Error + Send + Sync + 'static> From<Exn<T>> for DotExn {
+    fn from(exn: Exn<T>) -> Self {
+        Self(exn.into())
+    }
+}
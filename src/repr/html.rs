@@ -0,0 +1,137 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::string::{String, ToString};
+use core::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result, Write},
+};
+
+use crate::{
+    Exn, ExnAny, Frame, Repr,
+    visit::{self, TreeWriter},
+};
+
+/// [`ExnAny`] representation that renders the exception tree as nested HTML lists
+///
+/// The HTML is produced by [`ExnAny::to_html`](ExnAny#method.to_html) rather than by [`Display`],
+/// which is the message of the top-level [`Frame`] alone (or the entire exception tree in the
+/// alternate form, like [`Exn`]). The [`Debug`] representation is the same as that of [`Exn`].
+///
+/// Each [`Frame`] is rendered as an `<li>` element whose `class` is `exn-level-N`, where `N` is
+/// the depth of the [`Frame`], so that each level can be styled separately. The element contains
+/// the message of the [`Frame`] followed by its location in a `<code>` element, and its children
+/// are nested in a `<ul>` element. Messages and locations are HTML-escaped.
+///
+/// ```
+/// use std::io;
+///
+/// use exn::{ErrorExt, ExnAny, repr::Html};
+///
+/// let child = io::Error::other("a < b").raise();
+/// let exn: ExnAny<Html> = child.raise(io::Error::other("parent")).into();
+/// let html = exn.to_html();
+/// assert!(html.starts_with("<ul>\n<li class=\"exn-level-0\">parent <code>"));
+/// assert!(html.contains("<li class=\"exn-level-1\">a &lt; b <code>"));
+/// ```
+pub struct Html;
+
+impl Repr for Html {
+    type Impl<T: Error + Send + Sync + 'static> = HtmlExn;
+}
+
+impl ExnAny<Html> {
+    /// Renders the exception tree as nested HTML lists.
+    ///
+    /// See [`Html`] for details about the structure of the output.
+    #[must_use]
+    pub fn to_html(&self) -> String {
+        let exn = self
+            .error
+            .downcast_ref::<HtmlExn>()
+            .unwrap_or_else(|| unreachable!("error type must match"));
+        HtmlTree(&exn.0).to_string()
+    }
+}
+
+pub struct HtmlExn(Frame);
+
+impl Debug for HtmlExn {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        self.0.debug_full(f)
+    }
+}
+
+impl Display for HtmlExn {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Error for HtmlExn {}
+
+impl<T: Error + Send + Sync + 'static> From<Exn<T>> for HtmlExn {
+    fn from(exn: Exn<T>) -> Self {
+        Self(exn.into())
+    }
+}
+
+/// Renders a [`Frame`] and its descendants as nested HTML lists
+struct HtmlTree<'a>(&'a Frame);
+
+impl Display for HtmlTree<'_> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        f.write_str("<ul>\n")?;
+        visit::write_tree(f, self.0, ItemWriter)?;
+        f.write_str("</ul>")
+    }
+}
+
+/// Writes the `<li>` element for each [`Frame`]
+struct ItemWriter;
+
+impl TreeWriter for ItemWriter {
+    fn enter(&mut self, f: &mut Formatter, frame: &Frame, depth: usize) -> Result {
+        write!(f, "<li class=\"exn-level-{depth}\">")?;
+        escape(f, &frame.to_string())?;
+        f.write_str(" <code>")?;
+        escape(f, &frame.source_location().to_string())?;
+        f.write_str("</code>")?;
+        if !frame.children().is_empty() {
+            f.write_str("\n<ul>\n")?;
+        }
+        Ok(())
+    }
+
+    fn leave(&mut self, f: &mut Formatter, frame: &Frame, _: usize) -> Result {
+        if !frame.children().is_empty() {
+            f.write_str("</ul>\n")?;
+        }
+        f.write_str("</li>\n")
+    }
+}
+
+fn escape(f: &mut Formatter, text: &str) -> Result {
+    for c in text.chars() {
+        match c {
+            '&' => f.write_str("&amp;")?,
+            '<' => f.write_str("&lt;")?,
+            '>' => f.write_str("&gt;")?,
+            '"' => f.write_str("&quot;")?,
+            '\'' => f.write_str("&#39;")?,
+            c => f.write_char(c)?,
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,85 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::{
+    error::Error,
+    fmt::{self, Debug, Display, Formatter},
+};
+
+use crate::{Exn, ExnAny, Frame, Repr};
+
+/// [`ExnAny`] representation that renders the exception tree as JSON
+///
+/// The [`Display`] representation is compact JSON, and the [`Debug`] representation is
+/// pretty-printed JSON. See the [`Serialize`] implementation for [`Frame`] for details about the
+/// structure of the output.
+///
+/// ```
+/// use std::io;
+///
+/// use exn::{ErrorExt, ExnAny, repr::Json};
+///
+/// let child = io::Error::other("child").raise();
+/// let exn: ExnAny<Json> = child.raise(io::Error::other("parent")).into();
+///
+/// let value = exn.to_json_value();
+/// assert_eq!(value["message"], "parent");
+/// assert_eq!(value["children"][0]["message"], "child");
+/// ```
+///
+/// [`Serialize`]: serde::Serialize
+pub struct Json;
+
+const INFALLIBLE: &str = "`Frame` serialization is infallible";
+
+impl Repr for Json {
+    type Impl<T: Error + Send + Sync + 'static> = JsonExn;
+}
+
+impl ExnAny<Json> {
+    /// Converts the exception tree into a [`serde_json::Value`].
+    #[must_use]
+    pub fn to_json_value(&self) -> serde_json::Value {
+        let json_exn = self
+            .error
+            .downcast_ref::<JsonExn>()
+            .unwrap_or_else(|| unreachable!("error type must match"));
+        serde_json::to_value(&json_exn.0).unwrap_or_else(|_| unreachable!("{INFALLIBLE}"))
+    }
+}
+
+pub struct JsonExn(Frame);
+
+impl Debug for JsonExn {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let json =
+            serde_json::to_string_pretty(&self.0).unwrap_or_else(|_| unreachable!("{INFALLIBLE}"));
+        f.write_str(&json)
+    }
+}
+
+impl Display for JsonExn {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let json = serde_json::to_string(&self.0).unwrap_or_else(|_| unreachable!("{INFALLIBLE}"));
+        f.write_str(&json)
+    }
+}
+
+impl Error for JsonExn {}
+
+impl<T: Error + Send + Sync + 'static> From<Exn<T>> for JsonExn {
+    fn from(exn: Exn<T>) -> Self {
+        Self(exn.into())
+    }
+}
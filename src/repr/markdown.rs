@@ -0,0 +1,91 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result},
+};
+
+use crate::{
+    Exn, Frame, Repr,
+    visit::{self, TreeWriter},
+};
+
+/// [`ExnAny`] representation that renders the exception tree as a nested Markdown list
+///
+/// Each [`Frame`] is rendered as a bullet of the form `- message (file:line)`, and each level of
+/// children is indented by two additional spaces. Messages are emitted verbatim, so any Markdown
+/// syntax within them is not escaped. Both the [`Debug`] and [`Display`] representations are the
+/// same.
+///
+/// ```
+/// use std::io;
+///
+/// use exn::{ErrorExt, ExnAny, repr::Markdown};
+///
+/// let child = io::Error::other("child").raise();
+/// let exn: ExnAny<Markdown> = child.raise(io::Error::other("parent")).into();
+/// let markdown = exn.to_string();
+/// assert!(markdown.starts_with("- parent ("));
+/// assert!(markdown.contains("\n  - child ("));
+/// ```
+///
+/// [`ExnAny`]: crate::ExnAny
+pub struct Markdown;
+
+impl Repr for Markdown {
+    type Impl<T: Error + Send + Sync + 'static> = MarkdownExn;
+}
+
+pub struct MarkdownExn(Frame);
+
+/// Writes the bullet for each [`Frame`]
+struct ItemWriter;
+
+impl TreeWriter for ItemWriter {
+    fn enter(&mut self, f: &mut Formatter, frame: &Frame, depth: usize) -> Result {
+        if depth > 0 {
+            f.write_str("\n")?;
+        }
+        let location = frame.source_location();
+        write!(
+            f,
+            "{:indent$}- {frame} ({}:{})",
+            "",
+            location.file,
+            location.line,
+            indent = depth * 2,
+        )
+    }
+}
+
+impl Debug for MarkdownExn {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for MarkdownExn {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        visit::write_tree(f, &self.0, ItemWriter)
+    }
+}
+
+impl Error for MarkdownExn {}
+
+impl<T: Error + Send + Sync + 'static> From<Exn<T>> for MarkdownExn {
+    fn from(exn: Exn<T>) -> Self {
+        Self(exn.into())
+    }
+}
@@ -0,0 +1,214 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result},
+};
+
+use miette::{Diagnostic, LabeledSpan, SourceCode};
+
+use crate::{Exn, ExnAny, Frame, Repr, Span};
+
+/// [`ExnAny`] representation for interoperation with the [`miette`] crate
+///
+/// [`ExnAny<Miette>`] implements [`Diagnostic`], so it can be converted into a [`miette::Report`]
+/// (e.g., via `?` in a function that returns [`miette::Result`]). The children of each [`Frame`]
+/// are exposed as [related diagnostics], and the location of each [`Frame`] is exposed as its
+/// [help text]. The [`Span`] of each [`Frame`] (see [`Exn::with_span`]), if any, is exposed as its
+/// [source code] with a single [label]. The [`Display`] representation is the message of the
+/// top-level [`Frame`] (or the entire exception tree in the alternate form, like [`Exn`]), and the
+/// [`Debug`] representation is the same as that of [`Tree`].
+///
+/// ```no_run
+/// use std::io;
+///
+/// use exn::{ErrorExt, ExnAny, repr::Miette};
+///
+/// fn foo() -> Result<(), ExnAny<Miette>> {
+///     let child = io::Error::other("child").raise();
+///     Err(child.raise(io::Error::other("parent")).into())
+/// }
+///
+/// fn main() -> miette::Result<()> {
+///     foo()?;
+///     Ok(())
+/// }
+/// ```
+///
+/// [help text]: Diagnostic::help
+/// [label]: Diagnostic::labels
+/// [related diagnostics]: Diagnostic::related
+/// [source code]: Diagnostic::source_code
+/// [`Tree`]: crate::repr::Tree
+pub struct Miette;
+
+impl Repr for Miette {
+    type Impl<T: Error + Send + Sync + 'static> = MietteExn;
+}
+
+impl ExnAny<Miette> {
+    fn miette_exn(&self) -> &MietteExn {
+        self.error
+            .downcast_ref::<MietteExn>()
+            .unwrap_or_else(|| unreachable!("error type must match"))
+    }
+}
+
+impl Diagnostic for ExnAny<Miette> {
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.miette_exn().help()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.miette_exn().source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.miette_exn().labels()
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.miette_exn().related()
+    }
+}
+
+pub struct MietteExn {
+    frame: Frame,
+    children: Vec<MietteNode>,
+}
+
+impl Debug for MietteExn {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        self.frame.debug_full(f)
+    }
+}
+
+impl Display for MietteExn {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(&self.frame, f)
+    }
+}
+
+impl Error for MietteExn {}
+
+impl Diagnostic for MietteExn {
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(help(self.frame.source_location()))
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        source_code(self.frame.span())
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        labels(self.frame.span())
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        related(&self.children)
+    }
+}
+
+impl<T: Error + Send + Sync + 'static> From<Exn<T>> for MietteExn {
+    fn from(exn: Exn<T>) -> Self {
+        let frame = Frame::from(exn);
+        let children = frame.children().iter().map(MietteNode::from).collect();
+        Self { frame, children }
+    }
+}
+
+/// Type-erased descendant of a [`MietteExn`]
+///
+/// Unlike [`Frame`], this has no [`Error::source`], so that miette does not render the first child
+/// of each node as both a cause and a related diagnostic.
+struct MietteNode {
+    message: String,
+    location: String,
+    span: Option<Span>,
+    children: Vec<MietteNode>,
+}
+
+impl Debug for MietteNode {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Debug::fmt(&self.message, f)
+    }
+}
+
+impl Display for MietteNode {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for MietteNode {}
+
+impl Diagnostic for MietteNode {
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(help(&self.location))
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        source_code(self.span.as_ref())
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        labels(self.span.as_ref())
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        related(&self.children)
+    }
+}
+
+impl From<&Frame> for MietteNode {
+    fn from(frame: &Frame) -> Self {
+        Self {
+            message: frame.to_string(),
+            location: frame.source_location().to_string(),
+            span: frame.span().cloned(),
+            children: frame.children().iter().map(Self::from).collect(),
+        }
+    }
+}
+
+fn help(location: impl Display) -> Box<dyn Display> {
+    Box::new(format!("at {location}"))
+}
+
+fn source_code(span: Option<&Span>) -> Option<&dyn SourceCode> {
+    span.map(|span| &span.source as &dyn SourceCode)
+}
+
+fn labels(span: Option<&Span>) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+    span.map(|span| {
+        let label = LabeledSpan::new_with_span(Some(span.label.clone()), span.range.clone());
+        Box::new(core::iter::once(label)) as Box<dyn Iterator<Item = _>>
+    })
+}
+
+fn related<'a>(
+    children: &'a [MietteNode],
+) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+    (!children.is_empty()).then(|| {
+        Box::new(children.iter().map(|child| child as &dyn Diagnostic))
+            as Box<dyn Iterator<Item = _>>
+    })
+}
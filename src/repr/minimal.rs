@@ -0,0 +1,69 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result},
+};
+
+use crate::{Exn, Frame, Repr};
+
+/// [`ExnAny`] representation that renders only the root cause
+///
+/// The root cause is found like in [`Exn::root_cause`]. Both the [`Debug`] and [`Display`]
+/// representations print its message alone, without a location. This is suitable for end users who
+/// would be overwhelmed by the entire exception tree; consider switching to [`Tree`] when verbose
+/// output is requested.
+///
+/// ```
+/// use std::io;
+///
+/// use exn::{ErrorExt, ExnAny, repr::Minimal};
+///
+/// let child = io::Error::other("child").raise();
+/// let exn: ExnAny<Minimal> = child.raise(io::Error::other("parent")).into();
+/// assert_eq!(exn.to_string(), "child");
+/// ```
+///
+/// [`ExnAny`]: crate::ExnAny
+/// [`Tree`]: crate::repr::Tree
+pub struct Minimal;
+
+impl Repr for Minimal {
+    type Impl<T: Error + Send + Sync + 'static> = MinimalExn;
+}
+
+pub struct MinimalExn(Frame);
+
+impl Debug for MinimalExn {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for MinimalExn {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let root =
+            (self.0.spine().last()).unwrap_or_else(|| unreachable!("spine must not be empty"));
+        write!(f, "{root}")
+    }
+}
+
+impl Error for MinimalExn {}
+
+impl<T: Error + Send + Sync + 'static> From<Exn<T>> for MinimalExn {
+    fn from(exn: Exn<T>) -> Self {
+        Self(exn.into())
+    }
+}
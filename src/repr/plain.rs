@@ -0,0 +1,85 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result},
+};
+
+use crate::{
+    Exn, Frame, Repr,
+    visit::{self, TreeWriter},
+};
+
+/// [`ExnAny`] representation that renders the exception tree with indentation only
+///
+/// Each [`Frame`] is rendered on its own line like [`Frame::debug`], indented by two spaces per
+/// level of depth. Unlike [`Tree`], no connectors are printed, which is useful for log viewers that
+/// cannot render box-drawing characters. Both the [`Debug`] and [`Display`] representations are
+/// the same.
+///
+/// ```
+/// use std::io;
+///
+/// use exn::{ErrorExt, ExnAny, repr::Plain};
+///
+/// let child = io::Error::other("child").raise();
+/// let exn: ExnAny<Plain> = child.raise(io::Error::other("parent")).into();
+/// let plain = exn.to_string();
+/// assert!(plain.starts_with("parent, at "));
+/// assert!(plain.contains("\n  child, at "));
+/// ```
+///
+/// [`ExnAny`]: crate::ExnAny
+/// [`Tree`]: crate::repr::Tree
+pub struct Plain;
+
+impl Repr for Plain {
+    type Impl<T: Error + Send + Sync + 'static> = PlainExn;
+}
+
+pub struct PlainExn(Frame);
+
+/// Writes the line for each [`Frame`]
+struct LineWriter;
+
+impl TreeWriter for LineWriter {
+    fn enter(&mut self, f: &mut Formatter, frame: &Frame, depth: usize) -> Result {
+        if depth > 0 {
+            f.write_str("\n")?;
+        }
+        write!(f, "{:indent$}", "", indent = depth * 2)?;
+        frame.debug(f)
+    }
+}
+
+impl Debug for PlainExn {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for PlainExn {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        visit::write_tree(f, &self.0, LineWriter)
+    }
+}
+
+impl Error for PlainExn {}
+
+impl<T: Error + Send + Sync + 'static> From<Exn<T>> for PlainExn {
+    fn from(exn: Exn<T>) -> Self {
+        Self(exn.into())
+    }
+}
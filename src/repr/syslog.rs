@@ -0,0 +1,104 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result},
+};
+
+use crate::{Exn, Frame, Level, Repr};
+
+/// [`ExnAny`] representation that renders each [`Frame`] as an [RFC 5424] syslog message
+///
+/// [`Frame`]s are rendered in preorder, one message per line, in the following form:
+///
+/// ```text
+/// <PRI>1 - - - - - - message, at file:line:column
+/// ```
+///
+/// The facility is always `user` (`1`), and the severity is derived from the [`Level`] of each
+/// [`Frame`] (see [`Frame::level`]); [`Frame`]s without a [`Level`] have severity `err` (`3`).
+/// The header fields that are unknown to [`Exn`] (timestamp, hostname, etc.) are nil (`-`).
+/// Messages are emitted verbatim, so each line is only a single syslog message if no message
+/// contains a newline. Both the [`Debug`] and [`Display`] representations are the same.
+///
+/// ```
+/// use std::io;
+///
+/// use exn::{ErrorExt, ExnAny, Level, repr::Syslog};
+///
+/// let child = io::Error::other("child").raise();
+/// let exn: ExnAny<Syslog> = child
+///     .raise(io::Error::other("parent"))
+///     .with_level(Level::Warn)
+///     .into();
+/// let syslog = exn.to_string();
+/// assert!(syslog.starts_with("<12>1 - - - - - - parent, at "));
+/// assert!(syslog.contains("\n<11>1 - - - - - - child, at "));
+/// ```
+///
+/// [RFC 5424]: https://www.rfc-editor.org/rfc/rfc5424
+/// [`ExnAny`]: crate::ExnAny
+pub struct Syslog;
+
+impl Repr for Syslog {
+    type Impl<T: Error + Send + Sync + 'static> = SyslogExn;
+}
+
+pub struct SyslogExn(Frame);
+
+impl SyslogExn {
+    /// `user-level messages`
+    const FACILITY: u8 = 1;
+
+    const fn severity(level: Option<Level>) -> u8 {
+        match level {
+            None | Some(Level::Error) => 3,
+            Some(Level::Warn) => 4,
+            Some(Level::Info) => 6,
+            Some(Level::Debug | Level::Trace) => 7,
+        }
+    }
+}
+
+impl Debug for SyslogExn {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for SyslogExn {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        for (i, frame) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            let priority = Self::FACILITY * 8 + Self::severity(frame.level());
+            write!(
+                f,
+                "<{priority}>1 - - - - - - {frame}, at {}",
+                frame.source_location()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for SyslogExn {}
+
+impl<T: Error + Send + Sync + 'static> From<Exn<T>> for SyslogExn {
+    fn from(exn: Exn<T>) -> Self {
+        Self(exn.into())
+    }
+}
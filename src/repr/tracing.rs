@@ -0,0 +1,94 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result},
+};
+
+use crate::{Exn, ExnAny, Frame, FrameVisitor, Repr};
+
+/// [`ExnAny`] representation that can emit the exception tree as [`tracing`] events
+///
+/// The [`Debug`] and [`Display`] representations are the same as those of [`Tree`]. Call
+/// [`ExnAny::<Tracing>::emit`] to record one `ERROR`-level event per [`Frame`] in depth-first
+/// preorder. Each event has `file`, `line`, and `depth` fields, and its message is the [`Frame`]'s
+/// error. The root [`Frame`] has a `depth` of zero, so parent/child relationships can be
+/// reconstructed from the order and depth of consecutive events.
+///
+/// ```
+/// use std::io;
+///
+/// use exn::{ErrorExt, ExnAny, repr::Tracing};
+///
+/// let child = io::Error::other("child").raise();
+/// let exn: ExnAny<Tracing> = child.raise(io::Error::other("parent")).into();
+/// exn.emit();
+/// ```
+///
+/// [`Tree`]: crate::repr::Tree
+pub struct Tracing;
+
+impl Repr for Tracing {
+    type Impl<T: Error + Send + Sync + 'static> = TracingExn;
+}
+
+impl ExnAny<Tracing> {
+    /// Emits one [`tracing::error!`] event per [`Frame`] in the exception tree.
+    pub fn emit(&self) {
+        let tracing_exn = self
+            .error
+            .downcast_ref::<TracingExn>()
+            .unwrap_or_else(|| unreachable!("error type must match"));
+        tracing_exn.0.accept(&mut Emitter);
+    }
+}
+
+/// Emits an event for each [`Frame`]
+struct Emitter;
+
+impl FrameVisitor for Emitter {
+    fn enter(&mut self, frame: &Frame, depth: usize) {
+        let location = frame.source_location();
+        tracing::error!(
+            file = location.file,
+            line = location.line,
+            depth,
+            "{}",
+            frame,
+        );
+    }
+}
+
+pub struct TracingExn(Frame);
+
+impl Debug for TracingExn {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        self.0.debug_full(f)
+    }
+}
+
+impl Display for TracingExn {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Error for TracingExn {}
+
+impl<T: Error + Send + Sync + 'static> From<Exn<T>> for TracingExn {
+    fn from(exn: Exn<T>) -> Self {
+        Self(exn.into())
+    }
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::{
+use core::{
     error::Error,
     fmt::{Debug, Display, Formatter, Result},
 };
@@ -14,18 +14,36 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::error::Error;
+use core::{error::Error, fmt::Display};
 
-use crate::Exn;
+use crate::{Exn, Frame, Message};
 
 /// Reasonable return type to use throughout an application
-pub type Result<T, E> = std::result::Result<T, Exn<E>>;
+pub type Result<T, E> = core::result::Result<T, Exn<E>>;
+
+mod sealed {
+    /// Prevents [`ResultExt`](super::ResultExt) from being implemented outside of this crate, so
+    /// that methods can be added to it without breaking changes
+    pub trait Sealed {}
+
+    impl<T, E> Sealed for core::result::Result<T, E> {}
+}
 
 /// Propagating [`Result`]s with context
-pub trait ResultExt {
+///
+/// This trait is sealed and implemented for `Result<T, E>` and `Result<T, Exn<E>>` only.
+pub trait ResultExt: sealed::Sealed {
     /// [`Ok`] type
     type Success;
 
+    /// [`Err`] type
+    type Failure;
+
+    /// Error type of the top-level [`Frame`] that [`ResultExt::or_raise_when`] passes through
+    ///
+    /// This is `E` for both `Result<T, E>` and `Result<T, Exn<E>>`.
+    type Error: Error + Send + Sync + 'static;
+
     /// Raises the [`Err`] variant as a new [`Exn`] whose context is provided by `err`.
     #[expect(clippy::missing_errors_doc, reason = "similar to `Result::map_err`")]
     fn or_raise<A, B, F>(self, err: F) -> Result<Self::Success, B>
@@ -33,13 +51,139 @@ pub trait ResultExt {
         A: Into<B>,
         B: Error + Send + Sync + 'static,
         F: FnOnce() -> A;
+
+    /// Raises the [`Err`] variant as a new [`Exn`] whose context is provided by `err`, which
+    /// receives a reference to the original error.
+    ///
+    /// This is useful for context that interpolates the original error:
+    ///
+    /// ```no_run
+    /// use std::io::Error;
+    ///
+    /// use exn::{Result, ResultExt};
+    ///
+    /// fn main() -> Result<(), Error> {
+    ///     let config = std::fs::read_to_string("config.toml")
+    ///         .or_raise_with(|e| Error::other(format!("while loading config: {e}")))?;
+    /// #   drop(config);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[expect(clippy::missing_errors_doc, reason = "similar to `Result::map_err`")]
+    fn or_raise_with<A, B, F>(self, err: F) -> Result<Self::Success, B>
+    where
+        A: Into<B>,
+        B: Error + Send + Sync + 'static,
+        F: FnOnce(&Self::Failure) -> A;
+
+    /// Raises the [`Err`] variant as a new [`Exn`] whose context is provided by `err` if
+    /// `predicate` returns `true` for the original error.
+    ///
+    /// Otherwise, the original error is converted into `B` and becomes the error of a new [`Exn`]
+    /// without any additional context. If the original error is an [`Exn`], its top-level error is
+    /// converted into `B` in place, so no [`Frame`] is added and its location is retained. This
+    /// reduces noise for expected errors:
+    ///
+    /// ```
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use exn::{Result, ResultExt};
+    ///
+    /// fn read(path: &str) -> Result<String, Error> {
+    ///     std::fs::read_to_string(path).or_raise_when(
+    ///         |e| e.kind() != ErrorKind::NotFound,
+    ///         || Error::other(format!("failed to read {path}")),
+    ///     )
+    /// }
+    ///
+    /// let exn = read("does/not/exist").unwrap_err();
+    /// assert_eq!(exn.kind(), ErrorKind::NotFound);
+    /// assert!(exn.frame().children().is_empty());
+    /// ```
+    #[expect(clippy::missing_errors_doc, reason = "similar to `Result::map_err`")]
+    fn or_raise_when<P, A, B, F>(self, predicate: P, err: F) -> Result<Self::Success, B>
+    where
+        Self::Error: Into<B>,
+        P: FnOnce(&Self::Failure) -> bool,
+        A: Into<B>,
+        B: Error + Send + Sync + 'static,
+        F: FnOnce() -> A;
+
+    /// Raises the [`Err`] variant as a new [`Exn`] whose context is the given displayable value.
+    ///
+    /// This is equivalent to `self.or_raise(|| Message::new(context))`, so it doesn't require a
+    /// dedicated error type for simple context:
+    ///
+    /// ```no_run
+    /// use exn::{Message, Result, ResultExt};
+    ///
+    /// fn main() -> Result<(), Message> {
+    ///     let config = std::fs::read_to_string("config.toml").context("failed to load config")?;
+    /// #   drop(config);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[expect(clippy::missing_errors_doc, reason = "similar to `Result::map_err`")]
+    #[track_caller]
+    fn context<B, C>(self, context: C) -> Result<Self::Success, B>
+    where
+        Self: Sized,
+        B: Error + Send + Sync + 'static,
+        C: Display + Send + Sync + 'static,
+        Message: Into<B>,
+    {
+        self.or_raise(|| Message::new(context))
+    }
+
+    /// Raises the [`Err`] variant as a new [`Exn`] whose context is the displayable value
+    /// returned by `context`.
+    ///
+    /// This is the lazy equivalent of [`ResultExt::context`].
+    #[expect(clippy::missing_errors_doc, reason = "similar to `Result::map_err`")]
+    #[track_caller]
+    fn with_context<B, C, F>(self, context: F) -> Result<Self::Success, B>
+    where
+        Self: Sized,
+        B: Error + Send + Sync + 'static,
+        C: Display + Send + Sync + 'static,
+        F: FnOnce() -> C,
+        Message: Into<B>,
+    {
+        self.or_raise(|| Message::new(context()))
+    }
+
+    /// Raises the [`Err`] variant as a new [`Exn`] whose context is provided by `err` and whose
+    /// children are the original error followed by `siblings`.
+    ///
+    /// This combines [`ResultExt::or_raise`] and [`Exn::with_siblings`], so `siblings` may be
+    /// [`Exn`]s of any type or [`Frame`]s. `siblings` is only consumed if `self` is [`Err`].
+    #[expect(clippy::missing_errors_doc, reason = "similar to `Result::map_err`")]
+    #[track_caller]
+    fn or_raise_all<I, A, B, F>(self, siblings: I, err: F) -> Result<Self::Success, B>
+    where
+        Self: Sized,
+        I: IntoIterator,
+        I::Item: Into<Frame>,
+        A: Into<B>,
+        B: Error + Send + Sync + 'static,
+        F: FnOnce() -> A,
+    {
+        // Note: We can't use `Result::map_err` since `#[track_caller]` on closures is currently
+        // unstable.
+        match self.or_raise(err) {
+            Result::Ok(t) => Result::Ok(t),
+            Result::Err(exn) => Result::Err(exn.with_siblings(siblings)),
+        }
+    }
 }
 
-impl<T, E> ResultExt for std::result::Result<T, E>
+impl<T, E> ResultExt for core::result::Result<T, E>
 where
     E: Error + Send + Sync + 'static,
 {
     type Success = T;
+    type Failure = E;
+    type Error = E;
 
     #[track_caller]
     fn or_raise<A, B, F>(self, err: F) -> Result<Self::Success, B>
@@ -55,13 +199,47 @@ where
             Self::Err(e) => Result::Err(Exn::new(e).raise(err().into())),
         }
     }
+
+    #[track_caller]
+    fn or_raise_with<A, B, F>(self, err: F) -> Result<Self::Success, B>
+    where
+        A: Into<B>,
+        B: Error + Send + Sync + 'static,
+        F: FnOnce(&Self::Failure) -> A,
+    {
+        match self {
+            Self::Ok(t) => Result::Ok(t),
+            Self::Err(e) => {
+                let context = err(&e).into();
+                Result::Err(Exn::new(e).raise(context))
+            }
+        }
+    }
+
+    #[track_caller]
+    fn or_raise_when<P, A, B, F>(self, predicate: P, err: F) -> Result<Self::Success, B>
+    where
+        Self::Error: Into<B>,
+        P: FnOnce(&Self::Failure) -> bool,
+        A: Into<B>,
+        B: Error + Send + Sync + 'static,
+        F: FnOnce() -> A,
+    {
+        match self {
+            Self::Ok(t) => Result::Ok(t),
+            Self::Err(e) if predicate(&e) => Result::Err(Exn::new(e).raise(err().into())),
+            Self::Err(e) => Result::Err(Exn::new(e.into())),
+        }
+    }
 }
 
-impl<T, E> ResultExt for std::result::Result<T, Exn<E>>
+impl<T, E> ResultExt for core::result::Result<T, Exn<E>>
 where
     E: Error + Send + Sync + 'static,
 {
     type Success = T;
+    type Failure = Exn<E>;
+    type Error = E;
 
     #[track_caller]
     fn or_raise<A, B, F>(self, err: F) -> Result<Self::Success, B>
@@ -77,6 +255,93 @@ where
             Self::Err(e) => Result::Err(e.raise(err().into())),
         }
     }
+
+    #[track_caller]
+    fn or_raise_with<A, B, F>(self, err: F) -> Result<Self::Success, B>
+    where
+        A: Into<B>,
+        B: Error + Send + Sync + 'static,
+        F: FnOnce(&Self::Failure) -> A,
+    {
+        match self {
+            Self::Ok(t) => Result::Ok(t),
+            Self::Err(e) => {
+                let context = err(&e).into();
+                Result::Err(e.raise(context))
+            }
+        }
+    }
+
+    #[track_caller]
+    fn or_raise_when<P, A, B, F>(self, predicate: P, err: F) -> Result<Self::Success, B>
+    where
+        Self::Error: Into<B>,
+        P: FnOnce(&Self::Failure) -> bool,
+        A: Into<B>,
+        B: Error + Send + Sync + 'static,
+        F: FnOnce() -> A,
+    {
+        match self {
+            Self::Ok(t) => Result::Ok(t),
+            Self::Err(e) if predicate(&e) => Result::Err(e.raise(err().into())),
+            Self::Err(e) => Result::Err(e.map_into()),
+        }
+    }
+}
+
+/// Inspecting [`Result`]s with [`Exn`] errors
+pub trait ResultExnExt {
+    /// [`Ok`](core::result::Result::Ok) type
+    type Success;
+
+    /// Error type of the [`Exn`] in the [`Err`] variant
+    type Error: Error + Send + Sync + 'static;
+
+    /// Calls `f` with a reference to the [`Exn`] in the [`Err`] variant, then returns `self`
+    /// unchanged.
+    ///
+    /// This is like [`Result::inspect_err`] but only available for [`Exn`] errors, so the
+    /// argument of `f` need not be annotated to access methods of [`Exn`]. This is useful for
+    /// logging errors as they propagate:
+    ///
+    /// ```
+    /// use std::io::Error;
+    ///
+    /// use exn::{ErrorExt, Result, ResultExnExt};
+    ///
+    /// fn read() -> Result<(), Error> {
+    ///     Err(Error::other("failed").raise())
+    /// }
+    ///
+    /// fn run() -> Result<(), Error> {
+    ///     read().inspect_err_exn(|exn| eprintln!("{exn:?}"))?;
+    ///     Ok(())
+    /// }
+    ///
+    /// assert!(run().is_err());
+    /// ```
+    #[expect(
+        clippy::missing_errors_doc,
+        reason = "similar to `Result::inspect_err`"
+    )]
+    fn inspect_err_exn<F>(self, f: F) -> Result<Self::Success, Self::Error>
+    where
+        F: FnOnce(&Exn<Self::Error>);
+}
+
+impl<T, E: Error + Send + Sync + 'static> ResultExnExt for Result<T, E> {
+    type Success = T;
+    type Error = E;
+
+    fn inspect_err_exn<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&Exn<E>),
+    {
+        if let Err(exn) = &self {
+            f(exn);
+        }
+        self
+    }
 }
 
 /// Equivalent to `Ok::<_, Exn<E>>(value)`.
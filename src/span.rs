@@ -0,0 +1,38 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::string::String;
+use core::ops::Range;
+
+/// Labeled range of source input that a [`Frame`] refers to
+///
+/// See [`Exn::with_span`]. This is not rendered by [`Frame::debug_full`], but reporters (e.g.,
+/// `repr::Miette`) can use it to point at the offending input. With the `serde` feature, it is
+/// serialized along with its [`Frame`].
+///
+/// [`Exn::with_span`]: crate::Exn::with_span
+/// [`Frame`]: crate::Frame
+/// [`Frame::debug_full`]: crate::Frame::debug_full
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Span {
+    /// Source input (e.g., the contents of a file)
+    pub source: String,
+
+    /// Byte range within [`source`](Self::source)
+    pub range: Range<usize>,
+
+    /// Label that describes the range
+    pub label: String,
+}
@@ -0,0 +1,70 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+use tokio::task::JoinError;
+
+use crate::{Exn, PanicError};
+
+/// Converts a [`JoinError`] from a [`tokio`] task into an [`Exn`].
+///
+/// The [`Exn`]'s location is the callsite of this function. Panic locations are unavailable
+/// because the panic occurred in the task (see [`PanicError::location`]).
+///
+/// ```
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let handle = tokio::spawn(async { panic!("oh no") });
+/// let exn = exn::from_join_error(handle.await.unwrap_err());
+/// assert_eq!(exn.to_string(), "task panicked: oh no");
+/// # }
+/// ```
+///
+/// [`tokio`]: https://docs.rs/tokio
+#[must_use]
+#[track_caller]
+pub fn from_join_error(error: JoinError) -> Exn<TaskError> {
+    let error = match error.try_into_panic() {
+        Ok(payload) => TaskError::Panicked(PanicError::new(&*payload, None)),
+        Err(_) => TaskError::Cancelled,
+    };
+    Exn::new(error)
+}
+
+/// [`Error`] that represents a failed [`tokio`] task (see [`from_join_error`])
+///
+/// [`tokio`]: https://docs.rs/tokio
+#[derive(Debug)]
+pub enum TaskError {
+    /// The task panicked.
+    Panicked(PanicError),
+
+    /// The task was cancelled.
+    Cancelled,
+}
+
+impl Display for TaskError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Panicked(error) => write!(f, "task panicked: {error}"),
+            Self::Cancelled => f.write_str("task was cancelled"),
+        }
+    }
+}
+
+impl Error for TaskError {}
@@ -0,0 +1,107 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt::{self, Formatter};
+
+use crate::Frame;
+
+/// Visitor of the [`Frame`]s of an exception tree
+///
+/// [`Frame::accept`] (or [`Exn::visit`]) calls [`FrameVisitor::enter`] for each [`Frame`] in
+/// depth-first preorder, then visits its children, and then calls [`FrameVisitor::leave`] for the
+/// same [`Frame`]. The top-level [`Frame`] has a `depth` of zero. This is a convenient extension
+/// point for custom reporters, which would otherwise have to recurse through
+/// [`Frame::children`] themselves. Both methods do nothing by default.
+///
+/// ```
+/// use std::{fmt::Write, io};
+///
+/// use exn::{ErrorExt, Frame, FrameVisitor};
+///
+/// struct Outline(String);
+///
+/// impl FrameVisitor for Outline {
+///     fn enter(&mut self, frame: &Frame, depth: usize) {
+///         writeln!(self.0, "{:indent$}{frame}", "", indent = depth * 2).unwrap();
+///     }
+/// }
+///
+/// let child = io::Error::other("child").raise();
+/// let exn = child.raise(io::Error::other("parent"));
+/// let mut outline = Outline(String::new());
+/// exn.visit(&mut outline);
+/// assert_eq!(outline.0, "parent\n  child\n");
+/// ```
+///
+/// [`Exn::visit`]: crate::Exn::visit
+pub trait FrameVisitor {
+    /// Called for `frame` before any of its children are visited.
+    fn enter(&mut self, frame: &Frame, depth: usize) {
+        let _ = (frame, depth);
+    }
+
+    /// Called for `frame` after all of its children have been visited.
+    fn leave(&mut self, frame: &Frame, depth: usize) {
+        let _ = (frame, depth);
+    }
+}
+
+/// Fallible counterpart of [`FrameVisitor`] that writes to a [`Formatter`] (see [`write_tree`])
+pub(crate) trait TreeWriter {
+    /// Writes the part of `frame` that precedes its children.
+    fn enter(&mut self, f: &mut Formatter, frame: &Frame, depth: usize) -> fmt::Result;
+
+    /// Writes the part of `frame` that follows its children.
+    fn leave(&mut self, f: &mut Formatter, frame: &Frame, depth: usize) -> fmt::Result {
+        let _ = (f, frame, depth);
+        Ok(())
+    }
+}
+
+/// Writes `frame` and its descendants with `writer` via [`Frame::accept`].
+///
+/// Once `writer` fails, the rest of the tree is skipped and the error is returned.
+pub(crate) fn write_tree<W: TreeWriter>(
+    f: &mut Formatter,
+    frame: &Frame,
+    writer: W,
+) -> fmt::Result {
+    struct Visitor<'a, 'b, W> {
+        f: &'a mut Formatter<'b>,
+        writer: W,
+        result: fmt::Result,
+    }
+
+    impl<W: TreeWriter> FrameVisitor for Visitor<'_, '_, W> {
+        fn enter(&mut self, frame: &Frame, depth: usize) {
+            if self.result.is_ok() {
+                self.result = self.writer.enter(self.f, frame, depth);
+            }
+        }
+
+        fn leave(&mut self, frame: &Frame, depth: usize) {
+            if self.result.is_ok() {
+                self.result = self.writer.leave(self.f, frame, depth);
+            }
+        }
+    }
+
+    let mut visitor = Visitor {
+        f,
+        writer,
+        result: Ok(()),
+    };
+    frame.accept(&mut visitor);
+    visitor.result
+}
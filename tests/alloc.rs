@@ -0,0 +1,74 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Counts heap allocations to guard against regressions in the cost of creating an [`Exn`].
+
+mod generate;
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+use exn::Exn;
+
+use self::generate::Error;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn count_allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    let value = f();
+    let after = ALLOCATIONS.with(Cell::get);
+    drop(value);
+    after - before
+}
+
+#[test]
+fn allocations() {
+    // Warm up any lazily-initialized state (e.g., whether backtraces are enabled).
+    drop(Exn::new(Error("warm-up")));
+
+    #[cfg(feature = "backtrace")]
+    if Exn::new(Error("backtrace")).backtrace().is_some() {
+        // Capturing a backtrace allocates an unspecified number of times.
+        return;
+    }
+
+    // One for the error and one for the top-level frame.
+    assert_eq!(count_allocations(|| Exn::new(Error("E1"))), 2);
+
+    // One for each error, one for each non-empty list of children, and one for the top-level
+    // frame of each `Exn` that is created without raising (`Exn::raise` reuses it).
+    assert_eq!(count_allocations(generate::list::<Exn<Error>>), 10);
+    assert_eq!(count_allocations(generate::tree::<Exn<Error>>), 26);
+}
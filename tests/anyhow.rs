@@ -15,28 +15,31 @@
 mod generate;
 
 use anyhow::Context;
-use exn::repr::{Anyhow, List};
+use exn::{
+    ExnAny,
+    repr::{Anyhow, List},
+};
 
 #[test]
 fn list_repr_tree() {
-    let result = generate::list::<Anyhow>().context("context");
+    let result = generate::list::<ExnAny<Anyhow>>().context("context");
     insta::assert_compact_debug_snapshot!(result.unwrap_err());
 }
 
 #[test]
 fn list_repr_list() {
-    let result = generate::list::<Anyhow<List>>().context("context");
+    let result = generate::list::<ExnAny<Anyhow<List>>>().context("context");
     insta::assert_compact_debug_snapshot!(result.unwrap_err());
 }
 
 #[test]
 fn tree_repr_tree() {
-    let result = generate::tree::<Anyhow>().context("context");
+    let result = generate::tree::<ExnAny<Anyhow>>().context("context");
     insta::assert_compact_debug_snapshot!(result.unwrap_err());
 }
 
 #[test]
 fn tree_repr_list() {
-    let result = generate::tree::<Anyhow<List>>().context("context");
+    let result = generate::tree::<ExnAny<Anyhow<List>>>().context("context");
     insta::assert_compact_debug_snapshot!(result.unwrap_err());
 }
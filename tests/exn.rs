@@ -16,31 +16,31 @@
 
 mod generate;
 
-use exn::{Exn, OptionExt, Result, ResultExt, repr};
+use exn::{Exn, ExnAny, OptionExt, Result, ResultExt, repr};
 
 use self::generate::Error;
 
 #[test]
 fn list_repr_tree() {
-    let e = generate::list::<repr::Tree>().unwrap_err();
+    let e = generate::list::<ExnAny<repr::Tree>>().unwrap_err();
     insta::assert_compact_debug_snapshot!(e);
 }
 
 #[test]
 fn list_repr_list() {
-    let e = generate::list::<repr::List>().unwrap_err();
+    let e = generate::list::<ExnAny<repr::List>>().unwrap_err();
     insta::assert_compact_debug_snapshot!(e);
 }
 
 #[test]
 fn tree_repr_tree() {
-    let e = generate::tree::<repr::Tree>().unwrap_err();
+    let e = generate::tree::<ExnAny<repr::Tree>>().unwrap_err();
     insta::assert_compact_debug_snapshot!(e);
 }
 
 #[test]
 fn tree_repr_list() {
-    let e = generate::tree::<repr::List>().unwrap_err();
+    let e = generate::tree::<ExnAny<repr::List>>().unwrap_err();
     insta::assert_compact_debug_snapshot!(e);
 }
 
@@ -109,3 +109,1806 @@ fn ensure_fail() {
     let result = foo();
     insta::assert_compact_debug_snapshot!(result.unwrap_err());
 }
+
+#[test]
+fn frame_iter() {
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    let messages: Vec<_> = e.frame().iter().map(ToString::to_string).collect();
+    assert_eq!(
+        messages,
+        [
+            "E6", "E5", "E3", "E1", "E10", "E9", "E12", "E11", "E4", "E2", "E8", "E7"
+        ]
+    );
+}
+
+#[test]
+fn root_cause() {
+    let e = generate::list::<Exn<Error>>().unwrap_err();
+    assert_eq!(e.root_cause().to_string(), "E1");
+
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    assert_eq!(e.root_cause().to_string(), "E1");
+
+    let e = Exn::new(Error("leaf"));
+    assert_eq!(e.root_cause().to_string(), "leaf");
+}
+
+#[test]
+fn downcast_ref() {
+    #[derive(Debug, thiserror::Error)]
+    #[error("{0}")]
+    struct ErrorWithSource(&'static str, #[source] Error);
+
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    assert_eq!(e.downcast_ref::<Error>().unwrap().0, "E6");
+    assert!(e.downcast_ref::<std::io::Error>().is_none());
+
+    // Source errors are type-erased.
+    let e = Exn::new(ErrorWithSource("top", Error("source")));
+    assert_eq!(e.downcast_ref::<ErrorWithSource>().unwrap().0, "top");
+    assert!(e.downcast_ref::<Error>().is_none());
+}
+
+#[test]
+fn is() {
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    assert!(e.is::<Error>());
+    assert!(!e.is::<std::io::Error>());
+}
+
+#[cfg(feature = "backtrace")]
+#[test]
+fn backtrace() {
+    use std::backtrace::{Backtrace, BacktraceStatus};
+
+    let e = Exn::new(Error("E1"));
+    let enabled = Backtrace::capture().status() == BacktraceStatus::Captured;
+    assert_eq!(e.backtrace().is_some(), enabled);
+
+    // Raising retains the original backtrace.
+    let backtrace = e.backtrace().map(ToString::to_string);
+    let e = e.raise(Error("E2"));
+    assert_eq!(e.backtrace().map(ToString::to_string), backtrace);
+}
+
+#[test]
+fn attach() {
+    let child = Exn::new(Error("child")).attach("user", "bob");
+    let e = child
+        .raise(Error("parent"))
+        .attach("request_id", "abc")
+        .attach("attempt", 3);
+    assert_eq!(
+        e.frame().attachments(),
+        [
+            ("request_id".into(), "abc".to_string()),
+            ("attempt".into(), "3".to_string())
+        ]
+    );
+    insta::assert_compact_debug_snapshot!(e);
+}
+
+#[test]
+fn clone() {
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    let clone = e.clone();
+    assert_eq!(format!("{clone:?}"), format!("{e:?}"));
+    assert_eq!(clone.0, "E6");
+}
+
+#[test]
+fn result_ext_with() {
+    use std::io;
+
+    let result = Err::<(), _>(Error("An error"));
+    let result: Result<(), io::Error> =
+        result.or_raise_with(|e| io::Error::other(format!("context for {e}")));
+    insta::assert_compact_debug_snapshot!(result.unwrap_err());
+
+    let result = Err::<(), _>(Exn::new(Error("An error")));
+    let result: Result<(), io::Error> =
+        result.or_raise_with(|e| io::Error::other(format!("context for {}", e.0)));
+    insta::assert_compact_debug_snapshot!(result.unwrap_err());
+}
+
+#[test]
+fn result_ext_context() {
+    use exn::Message;
+
+    let result = Err::<(), _>(Error("An error"));
+    let result: Result<(), Message> = result.context("Some context");
+    insta::assert_compact_debug_snapshot!(result.unwrap_err());
+
+    let result = Err::<(), _>(Exn::new(Error("An error")));
+    let result: Result<(), Message> = result.with_context(|| format!("Context #{}", 2));
+    insta::assert_compact_debug_snapshot!(result.unwrap_err());
+}
+
+#[test]
+fn bail_format() {
+    use exn::Message;
+
+    fn foo() -> Result<(), Message> {
+        exn::bail!("An error");
+    }
+
+    fn bar(value: i32) -> Result<(), Message> {
+        exn::bail!("An error: {value}, {}", value + 1);
+    }
+
+    insta::assert_compact_debug_snapshot!(foo().unwrap_err());
+    insta::assert_compact_debug_snapshot!(bar(1).unwrap_err());
+}
+
+#[test]
+fn ensure_format() {
+    use exn::Message;
+
+    fn foo(value: i32) -> Result<(), Message> {
+        exn::ensure!(value > 0, "bad value: {}", value);
+        Ok(())
+    }
+
+    foo(1).unwrap();
+    insta::assert_compact_debug_snapshot!(foo(0).unwrap_err());
+}
+
+#[test]
+fn list_repr_compact() {
+    let e = generate::list::<ExnAny<repr::Compact>>().unwrap_err();
+    insta::assert_compact_debug_snapshot!(e);
+}
+
+#[test]
+fn tree_repr_compact() {
+    let e = generate::tree::<ExnAny<repr::Compact>>().unwrap_err();
+    insta::assert_compact_debug_snapshot!(e);
+}
+
+#[test]
+fn debug_full_colored() {
+    use std::fmt::{self, Debug, Formatter};
+
+    use exn::{ColorSpec, Frame};
+
+    struct Colored<'a>(&'a Frame, ColorSpec);
+
+    impl Debug for Colored<'_> {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            self.0.debug_full_colored(f, &self.1)
+        }
+    }
+
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+
+    let colored = format!("{:?}", Colored(e.frame(), ColorSpec::default()));
+    assert!(colored.starts_with("\x1b[1mE6\x1b[0m, at \x1b[2mtests/generate/mod.rs:"));
+    assert!(colored.contains("\x1b[90m├─ \x1b[0m"));
+
+    let plain = format!("{:?}", Colored(e.frame(), ColorSpec::NONE));
+    assert!(!plain.contains('\x1b'));
+    assert_eq!(plain, format!("{e:?}"));
+}
+
+#[test]
+fn chain() {
+    let e = generate::list::<Exn<Error>>().unwrap_err();
+    let messages: Vec<_> = e.chain().map(ToString::to_string).collect();
+    assert_eq!(messages, ["E5", "E4", "E3", "E2", "E1"]);
+
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    let messages: Vec<_> = e.chain().map(ToString::to_string).collect();
+    assert_eq!(messages, ["E6", "E5", "E3", "E1"]);
+}
+
+#[test]
+fn with_siblings() {
+    use exn::ErrorExt;
+
+    let e = Error("child").raise().raise(Error("primary"));
+    let e = e.with_siblings([Error("sibling 1").raise(), Error("sibling 2").raise()]);
+    assert_eq!(e.0, "primary");
+    insta::assert_compact_debug_snapshot!(e);
+}
+
+#[test]
+fn collect_all_capped() {
+    use std::cell::Cell;
+
+    use exn::IteratorExt;
+
+    let consumed = Cell::new(0);
+    let errors = (0..1000)
+        .map(|i| {
+            consumed.set(consumed.get() + 1);
+            Err::<(), _>(i)
+        })
+        .collect_all_capped::<Vec<_>, Vec<_>>(3)
+        .unwrap_err();
+    assert_eq!(errors, [0, 1, 2]);
+    assert_eq!(consumed.get(), 1000);
+}
+
+#[test]
+fn prune_depth() {
+    let e = generate::list::<Exn<Error>>().unwrap_err().prune_depth(2);
+    let messages: Vec<_> = e.frame().iter().map(ToString::to_string).collect();
+    assert_eq!(messages, ["E5", "E4", "E3", "... (2 more frames)"]);
+
+    let e = generate::tree::<Exn<Error>>().unwrap_err().prune_depth(1);
+    insta::assert_compact_debug_snapshot!(e);
+}
+
+#[test]
+fn depth_and_count() {
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    assert_eq!(e.depth(), 3);
+    assert_eq!(e.count(), 12);
+    assert_eq!(e.frame().children()[1].depth(), 1);
+    assert_eq!(e.frame().children()[1].count(), 2);
+
+    let e = Exn::new(Error("leaf"));
+    assert_eq!(e.depth(), 0);
+    assert_eq!(e.count(), 1);
+}
+
+#[test]
+fn into_boxed_error() {
+    let e = generate::list::<Box<dyn std::error::Error + Send + Sync>>().unwrap_err();
+    let messages: Vec<_> =
+        std::iter::successors(Some(&*e as &dyn std::error::Error), |e| e.source())
+            .map(ToString::to_string)
+            .collect();
+    assert_eq!(messages, ["E5", "E4", "E3", "E2", "E1"]);
+}
+
+#[test]
+fn find() {
+    use std::io;
+
+    use exn::ErrorExt;
+
+    let line = line!() + 1;
+    let child = Error("child").raise();
+    let e = child.raise(io::Error::other("parent"));
+
+    let frame = e.find::<Error>().unwrap();
+    assert_eq!(frame.downcast_ref::<Error>().unwrap().0, "child");
+    assert_eq!(frame.location().file(), "tests/exn.rs");
+    assert_eq!(frame.location().line(), line);
+    assert!(frame.downcast_ref::<io::Error>().is_none());
+
+    assert_eq!(e.find::<io::Error>().unwrap().to_string(), "parent");
+    assert!(e.find::<std::fmt::Error>().is_none());
+}
+
+#[test]
+fn deep_raise_chain() {
+    use exn::{ErrorExt, Message};
+
+    let mut e = Message::new(0).raise();
+    for i in 1..1000 {
+        e = e.raise(Message::new(i));
+    }
+    assert_eq!(e.to_string(), "999");
+    assert_eq!(e.depth(), 999);
+    assert_eq!(e.count(), 1000);
+    assert_eq!(e.chain().count(), 1000);
+    assert_eq!(e.root_cause().to_string(), "0");
+    assert!(e.frame().iter().all(|frame| frame.children().len() <= 1));
+}
+
+#[test]
+fn raise_at() {
+    use std::panic::Location;
+
+    use exn::ErrorExt;
+
+    #[track_caller]
+    fn helper(message: &'static str) -> Exn<Error> {
+        Error(message).raise_at(Location::caller())
+    }
+
+    let line = line!() + 1;
+    let e = helper("E1");
+    assert_eq!(e.frame().location().file(), "tests/exn.rs");
+    assert_eq!(e.frame().location().line(), line);
+
+    let location = Location::caller();
+    let e = Exn::new_at(Error("E2"), location);
+    assert_eq!(
+        format!("{e:?}"),
+        format!(
+            "E2, at tests/exn.rs:{}:{}",
+            location.line(),
+            location.column()
+        )
+    );
+}
+
+#[test]
+fn display_alternate() {
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    insta::assert_snapshot!(format!("{e}"));
+    insta::assert_snapshot!(format!("{e:#}"));
+
+    let e = generate::tree::<ExnAny>().unwrap_err();
+    assert_eq!(format!("{e}"), "E6");
+    assert_eq!(format!("{e:#}"), format!("{e:?}"));
+}
+
+#[test]
+fn list_repr_dot() {
+    let e = generate::list::<ExnAny<repr::Dot>>().unwrap_err();
+    insta::assert_snapshot!(e);
+}
+
+#[test]
+fn tree_repr_dot() {
+    let e = generate::tree::<ExnAny<repr::Dot>>().unwrap_err();
+    let dot = e.to_string();
+    assert!(dot.starts_with("digraph {\n"));
+    assert!(dot.ends_with('}'));
+    assert_eq!(dot.matches("->").count(), 11);
+    assert_eq!(dot.matches("[label=").count(), 12);
+    assert!(dot.contains("    n0 [label=\"E6\\nat tests/generate/mod.rs:44:9\"];\n"));
+    assert_eq!(format!("{e:?}"), dot);
+}
+
+#[test]
+fn option_ext_from() {
+    let result = None::<()>;
+    let result: Result<(), Error> = result.ok_or_raise_from(Error("An error"));
+    insta::assert_compact_debug_snapshot!(result.unwrap_err());
+
+    let result: Result<i32, Error> = Some(1).ok_or_raise_from(Error("An error"));
+    assert_eq!(result.unwrap(), 1);
+}
+
+#[test]
+fn frame_eq_and_hash() {
+    use std::collections::HashSet;
+
+    use exn::{ErrorExt, Frame};
+
+    let frames: HashSet<_> = (0..2).map(|_| Frame::from(Error("E").raise())).collect();
+    assert_eq!(frames.len(), 1);
+
+    let frames: HashSet<_> = ["E1", "E2"]
+        .into_iter()
+        .map(|message| Frame::from(Error(message).raise()))
+        .collect();
+    assert_eq!(frames.len(), 2);
+
+    let tree = || Frame::from(generate::tree::<Exn<Error>>().unwrap_err());
+    assert_eq!(tree(), tree());
+    assert_ne!(
+        tree(),
+        Frame::from(generate::list::<Exn<Error>>().unwrap_err())
+    );
+    assert_ne!(
+        tree(),
+        Frame::from(generate::tree::<Exn<Error>>().unwrap_err().attach("key", 1))
+    );
+}
+
+#[test]
+fn dedup_children() {
+    use std::io;
+
+    use exn::{ErrorExt, IteratorExt};
+
+    let errors: Vec<Exn<Error>> = (0..500)
+        .map(|i| {
+            let message = if i % 100 == 0 {
+                "No permission"
+            } else {
+                "No such file"
+            };
+            Err::<(), _>(Error(message).raise())
+        })
+        .collect_all::<Vec<_>, _>()
+        .unwrap_err();
+    let e = Exn::raise_all(errors, io::Error::other("failed to open files"));
+    assert_eq!(e.frame().children().len(), 500);
+
+    let e = e.dedup_children();
+    let multiplicities: Vec<_> = e
+        .frame()
+        .children()
+        .iter()
+        .map(|child| (child.to_string(), child.multiplicity()))
+        .collect();
+    assert_eq!(
+        multiplicities,
+        [("No permission".into(), 5), ("No such file".into(), 495)]
+    );
+    insta::assert_compact_debug_snapshot!(e);
+}
+
+#[test]
+fn tree_repr_markdown() {
+    let e = generate::tree::<ExnAny<repr::Markdown>>().unwrap_err();
+    insta::assert_snapshot!(e);
+}
+
+#[test]
+fn debug_with() {
+    use std::fmt::{self, Debug, Formatter};
+
+    use exn::{DebugOptions, Frame};
+
+    struct WithOptions<'a>(&'a Frame, DebugOptions<'a>);
+
+    impl Debug for WithOptions<'_> {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            self.0.debug_with(f, &self.1)
+        }
+    }
+
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    let plain = format!("{:?}", WithOptions(e.frame(), DebugOptions::default()));
+    assert_eq!(plain, format!("{e:?}"));
+
+    let options = DebugOptions {
+        strip_prefix: Some("tests"),
+        ..DebugOptions::default()
+    };
+    let stripped = format!("{:?}", WithOptions(e.frame(), options));
+    assert!(stripped.starts_with("E6, at generate/mod.rs:"));
+    assert!(!stripped.contains("tests/"));
+    assert_eq!(stripped.lines().count(), plain.lines().count());
+    assert_eq!(e.frame().location().file(), "tests/generate/mod.rs");
+
+    let options = DebugOptions {
+        strip_prefix: Some("src"),
+        relative: true,
+        ..DebugOptions::default()
+    };
+    let relative = format!("{:?}", WithOptions(e.frame(), options));
+    assert_eq!(relative, plain);
+}
+
+#[test]
+fn result_ext_all() {
+    use std::io;
+
+    use exn::ErrorExt;
+
+    let siblings = || [Error("E2").raise(), Error("E3").raise()];
+
+    let result: std::result::Result<(), _> = Err(io::Error::other("E1"));
+    let e: Exn<Error> = result
+        .or_raise_all(siblings(), || Error("parent"))
+        .unwrap_err();
+    let messages: Vec<_> = e.frame().iter().map(ToString::to_string).collect();
+    assert_eq!(messages, ["parent", "E1", "E2", "E3"]);
+
+    let result: Result<(), _> = Err(Error("E0").raise().raise(Error("E1")));
+    let e: Exn<Error> = result
+        .or_raise_all(siblings(), || Error("parent"))
+        .unwrap_err();
+    let messages: Vec<_> = e.frame().iter().map(ToString::to_string).collect();
+    assert_eq!(messages, ["parent", "E1", "E0", "E2", "E3"]);
+    assert_eq!(e.frame().children().len(), 3);
+
+    let result: Result<i32, Error> = Ok(1);
+    let result: Result<i32, Error> = result.or_raise_all(siblings(), || Error("parent"));
+    assert_eq!(result.unwrap(), 1);
+}
+
+#[test]
+fn map_err() {
+    #[derive(Debug, thiserror::Error)]
+    #[error("wrapped {0}")]
+    struct Wrapper(Error);
+
+    impl From<Error> for Wrapper {
+        fn from(error: Error) -> Self {
+            Self(error)
+        }
+    }
+
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    let location = e.frame().location();
+    let children: Vec<_> = e.frame().iter().skip(1).map(ToString::to_string).collect();
+
+    let e = e.attach("key", "value").map_err::<Wrapper>();
+    assert_eq!(e.0.0, "E6");
+    assert_eq!(e.to_string(), "wrapped E6");
+    assert_eq!(e.frame().location(), location);
+    assert_eq!(
+        e.frame().attachments(),
+        [("key".into(), "value".to_owned())]
+    );
+    assert_eq!(e.count(), 12);
+    let mapped: Vec<_> = e.frame().iter().skip(1).map(ToString::to_string).collect();
+    assert_eq!(mapped, children);
+}
+
+#[test]
+fn into_inner() {
+    use exn::ErrorExt;
+
+    let e = Error("E1").raise().raise(Error("E2"));
+    let error: Error = e.into_inner();
+    assert_eq!(error.0, "E2");
+}
+
+#[test]
+fn split() {
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    let expected: Vec<_> = e.frame().iter().map(ToString::to_string).collect();
+
+    let (error, children) = e.split();
+    assert_eq!(error.0, "E6");
+    assert_eq!(children.len(), 3);
+
+    let e = Exn::new(error).with_siblings(children);
+    let messages: Vec<_> = e.frame().iter().map(ToString::to_string).collect();
+    assert_eq!(messages, expected);
+
+    let (error, children) = e.split();
+    let e = Exn::raise_all(Vec::<Exn<Error>>::new(), error).with_siblings(children);
+    assert_eq!(e.count(), 12);
+}
+
+#[test]
+fn raise_into() {
+    use exn::ErrorExt;
+
+    #[derive(Debug, thiserror::Error)]
+    enum Low {
+        #[error("not found")]
+        NotFound,
+    }
+
+    #[derive(Debug, thiserror::Error)]
+    enum High {
+        #[error("low-level failure")]
+        Low(#[from] Low),
+    }
+
+    let e = Low::NotFound.raise().attach("key", "value");
+    let line = line!() + 1;
+    let e = e.raise_into::<High>();
+    assert!(matches!(*e, High::Low(Low::NotFound)));
+    assert_eq!(e.frame().location().line(), line);
+    assert_eq!(e.count(), 2);
+
+    let child = &e.frame().children()[0];
+    assert_eq!(child.to_string(), "not found");
+    assert_eq!(child.attachments(), [("key".into(), "value".to_owned())]);
+    assert!(child.downcast_ref::<Low>().is_none());
+}
+
+#[test]
+fn level() {
+    use exn::{ErrorExt, Level};
+
+    let e = Error("E1").raise().with_level(Level::Warn);
+    assert_eq!(e.frame().level(), Some(Level::Warn));
+
+    let e = e.raise(Error("E2")).with_level(Level::Error);
+    assert_eq!(e.frame().level(), Some(Level::Error));
+    assert_eq!(e.frame().children()[0].level(), Some(Level::Warn));
+    assert!(Level::Warn < Level::Error);
+    insta::assert_compact_debug_snapshot!(e);
+
+    let e = Error("E3").raise();
+    assert_eq!(e.frame().level(), None);
+    assert!(!format!("{e:?}").contains('['));
+}
+
+#[test]
+fn code() {
+    use exn::ErrorExt;
+
+    let e = Error("E1").raise();
+    assert_eq!(e.code(), None);
+
+    let e = e.with_code(2);
+    assert_eq!(e.code(), Some(2));
+    assert_eq!(e.frame().code(), Some(2));
+
+    let e = e.raise(Error("E2"));
+    assert_eq!(e.code(), Some(2));
+    assert_eq!(e.frame().code(), None);
+
+    let e = e.with_code(3);
+    assert_eq!(e.code(), Some(3));
+
+    let e = Exn::raise_all([Error("E3").raise().with_code(4)], Error("E4"));
+    assert_eq!(e.code(), Some(4));
+    let e = Exn::new(Error("E5")).with_siblings([e]);
+    assert_eq!(e.code(), Some(4));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn catch() {
+    assert_eq!(exn::catch(|| 42).unwrap(), 42);
+
+    let exn = exn::catch(|| panic!("oh no")).unwrap_err();
+    assert_eq!(exn.to_string(), "oh no");
+    assert_eq!(exn.message(), Some("oh no"));
+    assert!(
+        exn.location()
+            .is_some_and(|location| location.starts_with("tests/exn.rs:"))
+    );
+    assert_eq!(exn.frame().location().file(), "tests/exn.rs");
+
+    let exn = exn::catch(|| panic!("{}", 42)).unwrap_err();
+    assert_eq!(exn.to_string(), "42");
+
+    let exn = exn::catch(|| std::panic::panic_any(42)).unwrap_err();
+    assert_eq!(exn.to_string(), "Box<dyn Any>");
+    assert_eq!(exn.message(), None);
+}
+
+#[test]
+fn with_source() {
+    #[derive(Debug, thiserror::Error)]
+    #[error("{0}")]
+    struct ErrorWithSource(&'static str, #[source] Error);
+
+    let exn = Exn::new(Error("outer")).with_source(std::io::Error::other("io"));
+    let children = exn.frame().children();
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].to_string(), "io");
+    assert!(children[0].downcast_ref::<std::io::Error>().is_some());
+    assert_eq!(children[0].location().line(), line!() - 5);
+
+    let exn = exn.with_source(ErrorWithSource("with source", Error("source")));
+    let children = exn.frame().children();
+    assert_eq!(children.len(), 2);
+    assert_eq!(children[1].to_string(), "with source");
+    assert_eq!(children[1].children().len(), 1);
+    assert_eq!(children[1].children()[0].to_string(), "source");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn into_io_error() {
+    let e = generate::list::<std::io::Error>().unwrap_err();
+    assert_eq!(e.kind(), std::io::ErrorKind::Other);
+    assert!(e.get_ref().unwrap().is::<exn::Frame>());
+    let messages: Vec<_> =
+        std::iter::successors(Some(&e as &dyn std::error::Error), |e| e.source())
+            .map(ToString::to_string)
+            .collect();
+    assert_eq!(messages, ["E5", "E4", "E3", "E2", "E1"]);
+}
+
+#[test]
+fn debug_with_connectors_and_locations() {
+    use std::fmt::{self, Debug, Formatter};
+
+    use exn::{Connectors, DebugOptions, Frame};
+
+    struct WithOptions<'a>(&'a Frame, DebugOptions<'a>);
+
+    impl Debug for WithOptions<'_> {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            self.0.debug_with(f, &self.1)
+        }
+    }
+
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    let options = DebugOptions {
+        connectors: Connectors::ASCII,
+        locations: false,
+        ..DebugOptions::default()
+    };
+    insta::assert_snapshot!(format!("{:?}", WithOptions(e.frame(), options)), @r"
+    E6
+    |- E5
+    |  |- E3
+    |  |  `- E1
+    |  |- E10
+    |  |  `- E9
+    |  `- E12
+    |     `- E11
+    |- E4
+    |  `- E2
+    `- E8
+       `- E7
+    ");
+
+    let options = DebugOptions {
+        strip_prefix: Some("tests/generate/"),
+        location_format: Some(|f, file, line, _| write!(f, "{file}@{line}")),
+        ..DebugOptions::default()
+    };
+    let formatted = format!("{:?}", WithOptions(e.frame(), options));
+    assert!(formatted.starts_with("E6, at mod.rs@"));
+    assert!(formatted.contains("└─ E8, at mod.rs@"));
+}
+
+#[test]
+fn partition_all() {
+    use exn::IteratorExt;
+
+    let (oks, errs): (Vec<_>, Vec<_>) = (0..10)
+        .map(|i| if i % 3 == 0 { Err(i) } else { Ok(i) })
+        .partition_all();
+    assert_eq!(oks, [1, 2, 4, 5, 7, 8]);
+    assert_eq!(errs, [0, 3, 6, 9]);
+
+    let (oks, errs): (Vec<i32>, Vec<i32>) =
+        std::iter::empty::<std::result::Result<i32, i32>>().partition_all();
+    assert!(oks.is_empty());
+    assert!(errs.is_empty());
+}
+
+#[test]
+fn sort_by_location() {
+    fn lines(frames: &[exn::Frame]) -> Vec<u32> {
+        frames.iter().map(|frame| frame.location().line()).collect()
+    }
+
+    let e1 = Exn::new(Error("E1"));
+    let e2 = Exn::new(Error("E2"));
+    let e3 = Exn::new(Error("E3"));
+    let e4 = Exn::raise_all([e3, e1], Error("E4"));
+    let mut e5 = Exn::raise_all([e4, e2], Error("E5"));
+
+    let top = e5.frame().children();
+    assert!(top[0].location().line() > top[1].location().line());
+
+    e5.sort_children_by_location();
+    let top = e5.frame().children();
+    assert_eq!(top[0].to_string(), "E2");
+    assert_eq!(top[1].to_string(), "E4");
+    let nested = top[1].children();
+    assert!(lines(nested)[0] > lines(nested)[1]);
+
+    e5.sort_tree_by_location();
+    let top = e5.frame().children();
+    let nested = top[1].children();
+    assert_eq!(nested[0].to_string(), "E1");
+    assert_eq!(nested[1].to_string(), "E3");
+    assert!(lines(top).is_sorted() && lines(nested).is_sorted());
+}
+
+#[test]
+fn try_from_frame() {
+    use std::io;
+
+    let e = generate::list::<Exn<Error>>().unwrap_err();
+    let expected = format!("{e:?}");
+
+    let frame = exn::Frame::from(e);
+    let frame = Exn::<io::Error>::try_from_frame(frame).unwrap_err();
+    assert_eq!(format!("{frame:?}"), expected.lines().next().unwrap());
+
+    let e = Exn::<Error>::try_from_frame(frame).unwrap();
+    assert_eq!(e.0, "E5");
+    assert_eq!(format!("{e:?}"), expected);
+}
+
+#[test]
+fn list_repr_syslog() {
+    let e = generate::list::<Exn<Error>>().unwrap_err();
+    let e = e.raise(Error("E6")).with_level(exn::Level::Info);
+    let e = ExnAny::<repr::Syslog>::from(e);
+    let lines: Vec<_> = e.to_string().lines().map(str::to_owned).collect();
+    assert_eq!(lines.len(), 6);
+    assert!(lines[0].starts_with("<14>1 - - - - - - E6, at tests/exn.rs:"));
+    assert!(lines[1].starts_with("<11>1 - - - - - - E5, at tests/generate/mod.rs:"));
+    assert!(lines[5].starts_with("<11>1 - - - - - - E1, at tests/generate/mod.rs:"));
+}
+
+#[test]
+fn flatten() {
+    let e = generate::list::<Exn<Error>>().unwrap_err();
+    let location = *e.frame().iter().last().unwrap().location();
+    let frame = e.flatten();
+    assert_eq!(frame.to_string(), "E5: E4: E3: E2: E1");
+    assert_eq!(*frame.location(), location);
+    assert!(frame.children().is_empty());
+
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    let frame = e.flatten();
+    let messages: Vec<_> = frame.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        messages,
+        [
+            "E6", "E5", "E3: E1", "E10: E9", "E12: E11", "E4: E2", "E8: E7"
+        ]
+    );
+}
+
+#[test]
+fn auto_traits() {
+    use std::io;
+
+    use exn::Frame;
+
+    // These only need to compile.
+    const fn assert_send_sync_static<T: Send + Sync + 'static>() {}
+
+    const _: () = {
+        assert_send_sync_static::<Exn<io::Error>>();
+        assert_send_sync_static::<Exn<Error>>();
+        assert_send_sync_static::<Frame>();
+        assert_send_sync_static::<ExnAny>();
+        assert_send_sync_static::<ExnAny<repr::Compact>>();
+        assert_send_sync_static::<ExnAny<repr::Dot>>();
+        assert_send_sync_static::<ExnAny<repr::List>>();
+        assert_send_sync_static::<ExnAny<repr::Markdown>>();
+        assert_send_sync_static::<ExnAny<repr::Syslog>>();
+        assert_send_sync_static::<ExnAny<repr::Tree>>();
+        assert_send_sync_static::<repr::Anyhow>();
+    };
+}
+
+#[test]
+fn entries() {
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    let entries: Vec<_> = e
+        .entries()
+        .map(|(message, location)| format!("{message}@{}", location.file()))
+        .collect();
+    let expected: Vec<_> = [
+        "E6", "E5", "E3", "E1", "E10", "E9", "E12", "E11", "E4", "E2", "E8", "E7",
+    ]
+    .into_iter()
+    .map(|message| format!("{message}@tests/generate/mod.rs"))
+    .collect();
+    assert_eq!(entries, expected);
+
+    let lines: Vec<_> = e.entries().map(|(_, location)| location.line()).collect();
+    let frame_lines: Vec<_> = e
+        .frame()
+        .iter()
+        .map(|frame| frame.location().line())
+        .collect();
+    assert_eq!(lines, frame_lines);
+}
+
+#[test]
+fn new_chaining() {
+    #[derive(Debug, thiserror::Error)]
+    #[error("{0}")]
+    struct ErrorWithSource(&'static str, #[source] Error);
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("outer")]
+    struct Outer(#[from] ErrorWithSource);
+
+    let error = || Outer::from(ErrorWithSource("middle", Error("inner")));
+
+    let e = Exn::new_chaining::<Error>(error());
+    let messages: Vec<_> = e.frame().iter().map(ToString::to_string).collect();
+    assert_eq!(messages, ["outer", "middle", "inner"]);
+    assert!(e.find::<ErrorWithSource>().is_none());
+    assert_eq!(
+        e.find::<Error>()
+            .unwrap()
+            .downcast_ref::<Error>()
+            .unwrap()
+            .0,
+        "inner"
+    );
+
+    let e = Exn::new(error());
+    assert!(e.find::<Error>().is_none());
+}
+
+#[test]
+fn amend() {
+    let e = generate::list::<Exn<Error>>().unwrap_err();
+    let children = format!("{:?}", e.frame().children()[0]);
+    let debug = format!("{e:?}");
+
+    let e = e.amend("detail").amend(42);
+    assert_eq!(e.to_string(), "E5: detail: 42");
+    assert_eq!(e.frame().to_string(), "E5: detail: 42");
+    assert_eq!(e.0, "E5");
+    assert_eq!(format!("{:?}", e.frame().children()[0]), children);
+    assert_eq!(e.frame().count(), 5);
+
+    let mut expected = debug.lines();
+    let first = expected.next().unwrap().replacen("E5", "E5: detail: 42", 1);
+    let actual = format!("{e:?}");
+    let mut actual = actual.lines();
+    assert_eq!(actual.next().unwrap(), first);
+    assert!(actual.eq(expected));
+}
+
+#[test]
+fn to_tree_string() {
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    let tree = e.to_tree_string();
+    assert_eq!(e.frame().to_tree_string(), tree);
+    assert_eq!(tree, format!("{:?}", ExnAny::<repr::Tree>::from(e)));
+    assert_eq!(tree.lines().count(), 12);
+    assert!(!format!("{:?}", generate::tree::<Exn<Error>>().unwrap_err().frame()).contains('\n'));
+}
+
+#[test]
+fn transpose_raise() {
+    use exn::{ErrorExt, OptionExnExt};
+
+    let none: Option<Exn<Error>> = None;
+    let result: Result<(), Error> = none.transpose_raise(|| Error("parent"));
+    assert!(result.is_ok());
+
+    let e: Exn<Error> = Some(Error("child").raise())
+        .transpose_raise(|| Error("parent"))
+        .unwrap_err();
+    assert_eq!(e.0, "parent");
+    assert_eq!(e.frame().location().line(), line!() - 3);
+    let messages: Vec<_> = e.frame().iter().map(ToString::to_string).collect();
+    assert_eq!(messages, ["parent", "child"]);
+}
+
+#[test]
+fn retain() {
+    use std::error::Error as _;
+
+    let from_generate = |frame: &exn::Frame| frame.location().file() == "tests/generate/mod.rs";
+
+    let e1 = Exn::new(Error("E1"));
+    let e2 = generate::list::<Exn<Error>>().unwrap_err();
+    let mut e3 = Exn::raise_all([e2, e1], Error("E3"));
+    let mut e4 = Exn::raise_all([e3.clone()], Error("E4"));
+
+    e3.retain_children(|frame| !from_generate(frame));
+    let frame = exn::Frame::from(e3);
+    let messages: Vec<_> = frame.iter().map(ToString::to_string).collect();
+    assert_eq!(messages, ["E3", "E1"]);
+    assert_eq!(frame.source().unwrap().to_string(), "E1");
+
+    e4.retain_all(|frame| !from_generate(frame));
+    let messages: Vec<_> = e4.frame().iter().map(ToString::to_string).collect();
+    assert_eq!(messages, ["E4", "E3", "E1"]);
+}
+
+#[test]
+fn collect_all_keyed() {
+    use std::collections::HashMap;
+
+    use exn::KeyedIteratorExt;
+
+    let items = || [("a", Ok(1)), ("b", Err("x")), ("c", Err("y"))].into_iter();
+    let errors = items()
+        .collect_all_keyed::<HashMap<_, _>, HashMap<_, _>>()
+        .unwrap_err();
+    assert_eq!(errors, HashMap::from([("b", "x"), ("c", "y")]));
+
+    let values = items()
+        .filter(|(_, result)| result.is_ok())
+        .collect_all_keyed::<HashMap<_, _>, HashMap<_, _>>()
+        .unwrap();
+    assert_eq!(values, HashMap::from([("a", 1)]));
+}
+
+#[test]
+fn debug_normalized() {
+    use std::fmt::{self, Debug, Formatter};
+
+    use exn::{DebugOptions, Frame};
+
+    struct Normalized<'a>(&'a Frame);
+
+    impl Debug for Normalized<'_> {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            self.0.debug_with(f, &DebugOptions::normalized())
+        }
+    }
+
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    insta::assert_snapshot!(format!("{:?}", Normalized(e.frame())), @r"
+    E6, at tests/generate/mod.rs
+    ├─ E5, at tests/generate/mod.rs
+    │  ├─ E3, at tests/generate/mod.rs
+    │  │  └─ E1, at tests/generate/mod.rs
+    │  ├─ E10, at tests/generate/mod.rs
+    │  │  └─ E9, at tests/generate/mod.rs
+    │  └─ E12, at tests/generate/mod.rs
+    │     └─ E11, at tests/generate/mod.rs
+    ├─ E4, at tests/generate/mod.rs
+    │  └─ E2, at tests/generate/mod.rs
+    └─ E8, at tests/generate/mod.rs
+       └─ E7, at tests/generate/mod.rs
+    ");
+}
+
+#[test]
+fn summary() {
+    use exn::ErrorExt;
+
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    assert_eq!(e.summary(), "E6 (+11 causes)");
+
+    let e = Error("E1").raise();
+    assert_eq!(e.summary(), "E1");
+    assert_eq!(e.raise(Error("E2")).summary(), "E2 (+1 cause)");
+}
+
+#[test]
+fn locations() {
+    let e = generate::list::<Exn<Error>>().unwrap_err();
+    assert_eq!(e.first_location(), e.frame().location());
+
+    let locations: Vec<_> = e.all_locations().collect();
+    assert_eq!(locations.len(), 5);
+    assert_eq!(locations[0], e.first_location());
+    assert!(
+        locations
+            .iter()
+            .all(|location| location.file() == "tests/generate/mod.rs")
+    );
+    let lines: Vec<_> = locations.iter().map(|location| location.line()).collect();
+    let first = lines[4];
+    assert_eq!(lines, [first + 4, first + 3, first + 2, first + 1, first]);
+}
+
+#[test]
+fn raise_all_dyn() {
+    use std::{fmt, io};
+
+    use exn::{ErrorExt, Frame};
+
+    let children = [
+        Frame::from(io::Error::other("io").raise()),
+        Frame::from(fmt::Error.raise()),
+        Frame::from(Error("E1").raise()),
+    ];
+    let e = Exn::raise_all_dyn(children, Error("E2"));
+    assert_eq!(e.frame().location().line(), line!() - 1);
+
+    let children = e.frame().children();
+    assert_eq!(children.len(), 3);
+    assert!(children[0].downcast_ref::<io::Error>().is_some());
+    assert!(children[1].downcast_ref::<fmt::Error>().is_some());
+    assert_eq!(children[2].downcast_ref::<Error>().unwrap().0, "E1");
+}
+
+#[test]
+fn push_child() {
+    use exn::ErrorExt;
+
+    let mut e = Error("root").raise();
+    for name in ["E1", "E2", "E3"] {
+        e.push_child(Error(name).raise());
+    }
+    let messages: Vec<_> = e.frame().iter().map(ToString::to_string).collect();
+    assert_eq!(messages, ["root", "E1", "E2", "E3"]);
+    assert!(
+        e.frame()
+            .children()
+            .iter()
+            .all(|child| child.children().is_empty())
+    );
+}
+
+#[test]
+fn child_count() {
+    use exn::ErrorExt;
+
+    let e = Error("E1").raise();
+    assert!(!e.has_children());
+    assert_eq!(e.child_count(), 0);
+
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    assert!(e.has_children());
+    assert_eq!(e.child_count(), 3);
+}
+
+#[test]
+fn result_ext_when() {
+    use std::io;
+
+    let result: std::result::Result<(), _> = Err(io::Error::from(io::ErrorKind::NotFound));
+    let e: Exn<io::Error> = result
+        .or_raise_when(
+            |e| e.kind() != io::ErrorKind::NotFound,
+            || io::Error::other("context"),
+        )
+        .unwrap_err();
+    assert_eq!(e.kind(), io::ErrorKind::NotFound);
+    assert_eq!(e.frame().count(), 1);
+
+    let result: std::result::Result<(), _> = Err(io::Error::from(io::ErrorKind::PermissionDenied));
+    let e: Exn<io::Error> = result
+        .or_raise_when(
+            |e| e.kind() != io::ErrorKind::NotFound,
+            || io::Error::other("context"),
+        )
+        .unwrap_err();
+    let messages: Vec<_> = e.frame().iter().map(ToString::to_string).collect();
+    assert_eq!(messages, ["context", "permission denied"]);
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("wrapped {0}")]
+    struct Wrapped(Error);
+
+    impl From<Error> for Wrapped {
+        fn from(error: Error) -> Self {
+            Self(error)
+        }
+    }
+
+    let result: Result<(), Error> = Err(Exn::new(Error("E1")).raise(Error("E2")));
+    let location = result.as_ref().unwrap_err().frame().location();
+    let e: Exn<Wrapped> = result
+        .or_raise_when(|_| false, || Wrapped(Error("E3")))
+        .unwrap_err();
+    assert_eq!(e.messages(), ["wrapped E2", "E1"]);
+    assert_eq!(e.frame().location(), location);
+
+    let result: Result<(), Error> = Err(Exn::new(Error("E1")));
+    let e: Exn<Wrapped> = result
+        .or_raise_when(|_| true, || Wrapped(Error("E3")))
+        .unwrap_err();
+    assert_eq!(e.messages(), ["wrapped E3", "E1"]);
+}
+
+#[test]
+fn with_span() {
+    let e = Exn::new(Error("E1"));
+    assert!(e.frame().span().is_none());
+    let debug = format!("{e:?}");
+
+    let e = e.with_span("let x = ;", 8..9, "expected expression");
+    let span = e.frame().span().unwrap();
+    assert_eq!(span.source, "let x = ;");
+    assert_eq!(span.range, 8..9);
+    assert_eq!(span.label, "expected expression");
+    assert_eq!(format!("{e:?}"), debug);
+
+    let e = e.raise(Error("E2"));
+    assert!(e.frame().span().is_none());
+    assert_eq!(e.frame().children()[0].span().unwrap().range, 8..9);
+}
+
+#[test]
+fn list_repr_caused_by() {
+    let e = generate::list::<ExnAny<repr::CausedBy>>().unwrap_err();
+    assert_eq!(e.to_string(), "E5");
+    insta::assert_debug_snapshot!(e, @r"
+    E5, at tests/generate/mod.rs:55:12
+
+    Caused by:
+        0: E4, at tests/generate/mod.rs:54:17
+        1: E3, at tests/generate/mod.rs:53:17
+        2: E2, at tests/generate/mod.rs:52:17
+        3: E1, at tests/generate/mod.rs:51:26
+    ");
+
+    let e = ExnAny::<repr::CausedBy>::from(Exn::new(Error("E1")).raise(Error("E2")));
+    let rendered = format!("{e:?}");
+    assert!(
+        rendered.contains("\n\nCaused by:\n    E1, at tests/exn.rs:"),
+        "{rendered}"
+    );
+}
+
+#[test]
+fn debug_max_message_len() {
+    use std::{
+        fmt::{self, Debug, Formatter},
+        io,
+    };
+
+    use exn::{DebugOptions, Frame};
+
+    struct Truncated<'a>(&'a Frame);
+
+    impl Debug for Truncated<'_> {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            let options = DebugOptions {
+                locations: false,
+                max_message_len: Some(8),
+                ..DebugOptions::default()
+            };
+            self.0.debug_with(f, &options)
+        }
+    }
+
+    let child = Exn::new(io::Error::other("é".repeat(5000)));
+    let e = child.raise(io::Error::other("parent"));
+    insta::assert_snapshot!(format!("{:?}", Truncated(e.frame())), @r"
+    parent
+    └─ éééééééé…
+    ");
+    assert_eq!(e.frame().children()[0].to_string().chars().count(), 5000);
+}
+
+#[test]
+fn walk_mut() {
+    use std::io;
+
+    let mut frame = exn::Frame::from(generate::tree::<Exn<Error>>().unwrap_err());
+    let count = frame.count();
+    let mut visited = Vec::new();
+    frame.walk_mut(|frame| {
+        let message = frame.to_string();
+        frame.replace_error(io::Error::other(message.to_lowercase()));
+        visited.push(message);
+    });
+    let expected: Vec<_> = (frame.iter().map(|frame| frame.to_string().to_uppercase())).collect();
+    assert_eq!(visited, expected);
+    assert_eq!(frame.count(), count);
+    assert_eq!(frame.to_string(), "e6");
+
+    let mut e = generate::tree::<Exn<Error>>().unwrap_err();
+    e.walk_mut(|frame| {
+        frame.children_mut().clear();
+        frame.replace_error(io::Error::other("leaf"));
+    });
+    assert_eq!(e.to_string(), "E6");
+    assert_eq!(e.frame().count(), 4);
+    assert!(
+        e.frame()
+            .children()
+            .iter()
+            .all(|child| child.to_string() == "leaf")
+    );
+}
+
+#[test]
+fn into_frames() {
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    let count = e.frame().count();
+    let messages: Vec<_> = e.frame().iter().map(ToString::to_string).collect();
+
+    let frames = e.into_frames();
+    assert_eq!(frames.len(), count);
+    assert!(frames.iter().all(|frame| frame.children().is_empty()));
+    let flattened: Vec<_> = frames.iter().map(ToString::to_string).collect();
+    assert_eq!(flattened, messages);
+}
+
+#[test]
+fn bail_all() {
+    use exn::Message;
+
+    fn foo() -> Result<(), Error> {
+        let children = ["E1", "E2", "E3"].map(|message| Exn::new(Error(message)));
+        exn::bail_all!(children, Error("E4"));
+    }
+
+    fn bar(count: usize) -> Result<(), Message> {
+        let children = (0..count).map(|i| Exn::new(Message::new(format!("child {i}"))));
+        exn::bail_all!(children, "{count} failures");
+    }
+
+    let line = line!() - 8;
+    let e = foo().unwrap_err();
+    assert_eq!(e.to_string(), "E4");
+    assert_eq!(e.frame().location().line(), line);
+    let children: Vec<_> = e
+        .frame()
+        .children()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(children, ["E1", "E2", "E3"]);
+    assert!(
+        e.frame()
+            .children()
+            .iter()
+            .all(|child| child.children().is_empty())
+    );
+
+    let e = bar(3).unwrap_err();
+    assert_eq!(e.to_string(), "3 failures");
+    assert_eq!(e.frame().children().len(), 3);
+}
+
+#[test]
+fn ensure_all() {
+    use exn::ensure_all;
+
+    fn validate(values: [i32; 3]) -> Result<(), Error> {
+        ensure_all!(
+            [
+                (values[0] > 0, Error("E1")),
+                (values[1] > 0, Error("E2")),
+                (values[2] > 0, Error("E3")),
+            ],
+            Error("E4"),
+        );
+        Ok(())
+    }
+
+    assert!(validate([1, 2, 3]).is_ok());
+
+    let e = validate([-1, 2, -3]).unwrap_err();
+    assert_eq!(e.to_string(), "E4");
+    let children: Vec<_> = e
+        .frame()
+        .children()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(children, ["E1", "E3"]);
+
+    let result: Result<(), exn::Message> = (|| {
+        ensure_all!([(false, Error("E1"))], "{} failure", 1);
+        Ok(())
+    })();
+    assert_eq!(result.unwrap_err().to_string(), "1 failure");
+}
+
+#[test]
+fn count_by() {
+    use std::io;
+
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    assert_eq!(e.count_by::<Error>(), e.frame().count());
+    assert_eq!(e.count_by::<io::Error>(), 0);
+    assert_eq!(e.count_erased(), 0);
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("{0}")]
+    struct ErrorWithSource(&'static str, #[source] Error);
+
+    let e = Exn::new(ErrorWithSource("E1", Error("source"))).raise(io::Error::other("E2"));
+    assert_eq!(e.count_by::<ErrorWithSource>(), 1);
+    assert_eq!(e.count_by::<Error>(), 0);
+    assert_eq!(e.count_by::<io::Error>(), 1);
+    assert_eq!(e.count_erased(), 1);
+}
+
+#[test]
+fn tree_repr_html() {
+    use std::io;
+
+    let e = generate::tree::<ExnAny<repr::Html>>().unwrap_err();
+    assert_eq!(e.to_string(), "E6");
+    let html = e.to_html();
+    assert_eq!(html.matches("<li ").count(), 12);
+    assert_eq!(html.matches("<ul>").count(), html.matches("</ul>").count());
+    assert!(html.contains("exn-level-3"));
+    assert!(!html.contains("exn-level-4"));
+
+    let mut depth = 0;
+    let mut max_depth = 0;
+    for tag in html.split('<').filter_map(|s| s.split('>').next()) {
+        match tag {
+            "ul" => depth += 1,
+            "/ul" => depth -= 1,
+            _ => {}
+        }
+        max_depth = max_depth.max(depth);
+    }
+    assert_eq!(max_depth, 4);
+
+    let e = ExnAny::<repr::Html>::from(Exn::new(io::Error::other("<b>a & b</b>")));
+    let html = e.to_html();
+    assert!(
+        html.starts_with("<ul>\n<li class=\"exn-level-0\">&lt;b&gt;a &amp; b&lt;/b&gt; <code>"),
+        "{html}",
+    );
+    assert!(html.ends_with("</code></li>\n</ul>"), "{html}");
+}
+
+#[test]
+fn debug_max_children() {
+    use std::fmt::{self, Debug, Formatter};
+
+    use exn::{DebugOptions, Frame};
+
+    struct Limited<'a>(&'a Frame);
+
+    impl Debug for Limited<'_> {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            let options = DebugOptions {
+                locations: false,
+                max_children: Some(3),
+                ..DebugOptions::default()
+            };
+            self.0.debug_with(f, &options)
+        }
+    }
+
+    let children = (1..=10).map(|i| Exn::new(Error("child")).attach("index", i));
+    let e = Exn::raise_all(children, Error("parent"));
+    insta::assert_snapshot!(format!("{:?}", Limited(e.frame())), @r#"
+    parent
+    ├─ child
+    │     index = "1"
+    ├─ child
+    │     index = "2"
+    ├─ child
+    │     index = "3"
+    └─ ... and 7 more
+    "#);
+    assert_eq!(e.frame().children().len(), 10);
+}
+
+#[test]
+fn take_child() {
+    let children = ["E1", "E2", "E3"].map(|message| Exn::new(Error(message)));
+    let mut e = Exn::raise_all(children, Error("E4"));
+
+    let child = e.take_child(1).unwrap();
+    assert_eq!(child.to_string(), "E2");
+    assert_eq!(e.child_count(), 2);
+    let messages: Vec<_> = e
+        .frame()
+        .children()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(messages, ["E1", "E3"]);
+
+    let child = Exn::<Error>::try_from_frame(child).unwrap();
+    assert_eq!(child.0, "E2");
+    assert!(e.take_child(2).is_none());
+}
+
+#[test]
+fn display_alternate_repr() {
+    fn display<T: repr::Repr>() -> String {
+        let e = generate::list::<ExnAny<T>>().unwrap_err();
+        format!("{e}\n---\n{e:#}")
+    }
+
+    insta::assert_snapshot!("display_alternate_tree", display::<repr::Tree>());
+    insta::assert_snapshot!("display_alternate_list", display::<repr::List>());
+    insta::assert_snapshot!("display_alternate_caused_by", display::<repr::CausedBy>());
+    insta::assert_snapshot!("display_alternate_compact", display::<repr::Compact>());
+    insta::assert_snapshot!("display_alternate_dot", display::<repr::Dot>());
+    insta::assert_snapshot!("display_alternate_html", display::<repr::Html>());
+    insta::assert_snapshot!("display_alternate_markdown", display::<repr::Markdown>());
+    insta::assert_snapshot!("display_alternate_syslog", display::<repr::Syslog>());
+    insta::assert_snapshot!("display_alternate_anyhow", display::<repr::Anyhow>());
+}
+
+#[test]
+fn inspect_err_exn() {
+    use exn::ResultExnExt;
+
+    fn propagate(inspected: &mut Vec<String>) -> Result<(), Error> {
+        generate::list::<Exn<Error>>().inspect_err_exn(|e| inspected.push(e.summary()))?;
+        Ok(())
+    }
+
+    let mut inspected = Vec::new();
+    let e = propagate(&mut inspected).unwrap_err();
+    assert_eq!(inspected, ["E5 (+4 causes)"]);
+    assert_eq!(e.frame().count(), 5);
+
+    let result: Result<i32, Error> = exn::Ok(1);
+    let result = result.inspect_err_exn(|_| unreachable!());
+    assert_eq!(result.unwrap(), 1);
+}
+
+#[test]
+fn messages() {
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    assert_eq!(
+        e.messages(),
+        [
+            "E6", "E5", "E3", "E1", "E10", "E9", "E12", "E11", "E4", "E2", "E8", "E7"
+        ],
+    );
+    assert_eq!(e.frame().children()[1].messages(), ["E4", "E2"]);
+}
+
+#[test]
+fn tree_repr_plain() {
+    let e = generate::tree::<ExnAny<repr::Plain>>().unwrap_err();
+    insta::assert_snapshot!(e);
+}
+
+#[test]
+fn combine() {
+    let results = ["1", "2", "3"].map(|s| s.parse::<i32>().or_raise(|| Error("parse")));
+    let values = exn::combine(results, || Error("combine")).unwrap();
+    assert_eq!(values, [1, 2, 3]);
+
+    let results = ["1", "two", "three"].map(|s| s.parse::<i32>().or_raise(|| Error("parse")));
+    let line = line!() + 1;
+    let e = exn::combine(results, || Error("combine")).unwrap_err();
+    assert_eq!(e.to_string(), "combine");
+    assert_eq!(e.frame().location().line(), line);
+    assert_eq!(e.child_count(), 2);
+    assert!(
+        e.frame()
+            .children()
+            .iter()
+            .all(|child| child.to_string() == "parse")
+    );
+}
+
+#[test]
+fn root_location() {
+    let e = generate::list::<Exn<Error>>().unwrap_err();
+    let leaf = e.frame().iter().last().unwrap();
+    assert_eq!(leaf.to_string(), "E1");
+    assert_eq!(e.root_location(), leaf.location());
+    assert_ne!(e.root_location(), e.first_location());
+
+    let e = Exn::new(Error("E1"));
+    assert_eq!(e.root_location(), e.first_location());
+}
+
+#[test]
+fn for_each_all() {
+    use exn::ForEachAllExt;
+
+    let mut visited = Vec::new();
+    let errors: Vec<_> = (1..=5)
+        .for_each_all(|i| {
+            visited.push(i);
+            if i % 2 == 0 {
+                Err(Error(if i == 2 { "E2" } else { "E4" }))
+            } else {
+                Ok(())
+            }
+        })
+        .unwrap_err();
+    assert_eq!(visited, [1, 2, 3, 4, 5]);
+    let messages: Vec<_> = errors.iter().map(ToString::to_string).collect();
+    assert_eq!(messages, ["E2", "E4"]);
+
+    let result: std::result::Result<(), Vec<Error>> = (1..=5).for_each_all(|_| Ok(()));
+    assert!(result.is_ok());
+}
+
+#[test]
+fn with_help() {
+    let e = generate::list::<Exn<Error>>().unwrap_err();
+    assert_eq!(e.frame().help(), None);
+    let e = e.with_help("try again");
+    assert_eq!(e.frame().help(), Some("try again"));
+
+    let e = e.raise(Error("E6")).with_help("try harder");
+    let rendered = format!("{e:?}");
+    let lines: Vec<_> = rendered.lines().collect();
+    assert_eq!(lines.len(), 8);
+    assert!(lines[5].starts_with("└─ E1, at "));
+    assert_eq!(lines[6..], ["help: try harder", "help: try again"]);
+}
+
+#[test]
+fn from_iterator() {
+    #[derive(Debug, Default, thiserror::Error)]
+    #[error("aggregate")]
+    struct Aggregate;
+
+    let exns = [
+        Exn::new(Aggregate),
+        Exn::new(Aggregate),
+        Exn::new(Aggregate),
+    ];
+    let e: Exn<Aggregate> = exns.into_iter().collect();
+    assert_eq!(e.frame().children().len(), 3);
+
+    let e: Exn<Aggregate> = core::iter::empty().collect();
+    assert_eq!(e.to_string(), "aggregate");
+    assert!(e.frame().children().is_empty());
+}
+
+#[test]
+fn set_children() {
+    let children = ["E1", "E2", "E3"].map(|message| Exn::new(Error(message)));
+    let mut e = Exn::raise_all(children, Error("E4"));
+
+    let (_, mut children) = exn::Frame::from(e.clone()).consume();
+    children.reverse();
+    e.set_children(children);
+    let messages: Vec<_> = e
+        .frame()
+        .children()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_eq!(messages, ["E3", "E2", "E1"]);
+
+    e.set_children(Vec::new());
+    assert_eq!(e.child_count(), 0);
+}
+
+#[test]
+fn list_repr_minimal() {
+    let e = generate::list::<ExnAny<repr::Minimal>>().unwrap_err();
+    assert_eq!(e.to_string(), "E1");
+    assert_eq!(format!("{e:?}"), "E1");
+    assert_eq!(format!("{e:#}"), "E1");
+}
+
+#[test]
+fn raise_boxed() {
+    let context: Box<dyn std::error::Error + Send + Sync> = Box::new(Error("E2"));
+    let line = line!() + 1;
+    let e = Exn::new(Error("E1")).raise_boxed(context);
+    assert_eq!(e.to_string(), "E2");
+    assert!(e.frame().downcast_ref::<exn::BoxError>().is_some());
+    let tree = format!("{e:?}").replace(&format!("tests/exn.rs:{line}:"), "tests/exn.rs:_:");
+    insta::assert_snapshot!(tree, @r"
+    E2, at tests/exn.rs:_:35
+    └─ E1, at tests/exn.rs:_:13
+    ");
+}
+
+#[test]
+fn raise_with_children() {
+    use exn::ErrorExt;
+
+    let children = || ["E1", "E2"].map(|message| Exn::new(Error(message)));
+    let expected = Exn::raise_all(children(), Error("E3"));
+    let e = Error("E3").raise_with_children(children());
+    assert_eq!(e.messages(), expected.messages());
+    assert_eq!(e.frame().location().line(), line!() - 2);
+    assert_eq!(e.frame().children()[1].location().line(), line!() - 5);
+}
+
+#[test]
+fn log_line() {
+    let e = generate::list::<Exn<Error>>().unwrap_err();
+    assert_eq!(e.log_line(), "E5 <- E4 <- E3 <- E2 <- E1");
+    assert_eq!(e.log_line_with(7), "E5 <- E…");
+    assert_eq!(e.log_line_with(26), e.log_line());
+
+    let e = Exn::new(Error("line 1\nline 2")).raise(Error("x".repeat(250).leak()));
+    let line = e.log_line();
+    assert_eq!(line.chars().count(), exn::LOG_LINE_MAX_LEN + 1);
+    assert!(line.ends_with("x…"));
+    assert_eq!(e.log_line_with(300).lines().count(), 1);
+
+    let e = Exn::new(Error("a\r\nb")).raise(Error("c"));
+    assert_eq!(e.log_line(), "c <- a  b");
+}
+
+#[test]
+fn log_line_stops_at_max_len() {
+    #[derive(Debug)]
+    struct Endless;
+
+    impl std::fmt::Display for Endless {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            loop {
+                f.write_str("x")?;
+            }
+        }
+    }
+
+    impl std::error::Error for Endless {}
+
+    let line = Exn::new(Endless).log_line_with(3);
+    assert_eq!(line, "xxx…");
+}
+
+#[test]
+fn downcast() {
+    #[derive(Debug, thiserror::Error)]
+    #[error("other")]
+    struct Other;
+
+    let e = Exn::new(Error("E1")).raise(Error("E2"));
+    assert_eq!(e.downcast::<Error>().unwrap().0, "E2");
+
+    let e = Exn::new(Other).raise(Error("E2"));
+    let e = e.downcast::<Other>().unwrap_err();
+    assert_eq!(e.messages(), ["E2", "other"]);
+}
+
+#[test]
+fn visit() {
+    #[derive(Default)]
+    struct Counter {
+        enters: usize,
+        leaves: usize,
+        max_depth: usize,
+        stack: Vec<String>,
+    }
+
+    impl exn::FrameVisitor for Counter {
+        fn enter(&mut self, frame: &exn::Frame, depth: usize) {
+            assert_eq!(depth, self.stack.len());
+            self.enters += 1;
+            self.max_depth = self.max_depth.max(depth);
+            self.stack.push(frame.to_string());
+        }
+
+        fn leave(&mut self, frame: &exn::Frame, depth: usize) {
+            self.leaves += 1;
+            assert_eq!(self.stack.pop().unwrap(), frame.to_string());
+            assert_eq!(depth, self.stack.len());
+        }
+    }
+
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    let mut counter = Counter::default();
+    e.visit(&mut counter);
+    assert_eq!(counter.enters, 12);
+    assert_eq!(counter.leaves, 12);
+    assert_eq!(counter.max_depth, 3);
+    assert!(counter.stack.is_empty());
+}
+
+#[test]
+fn debug_with_partial_component_prefix() {
+    use std::fmt::{self, Debug, Formatter};
+
+    use exn::{DebugOptions, Frame};
+
+    struct WithOptions<'a>(&'a Frame, DebugOptions<'a>);
+
+    impl Debug for WithOptions<'_> {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            self.0.debug_with(f, &self.1)
+        }
+    }
+
+    let e = generate::tree::<Exn<Error>>().unwrap_err();
+    let plain = format!("{e:?}");
+    for prefix in ["test", "tests/gen"] {
+        let options = DebugOptions {
+            strip_prefix: Some(prefix),
+            ..DebugOptions::default()
+        };
+        assert_eq!(format!("{:?}", WithOptions(e.frame(), options)), plain);
+    }
+
+    let options = DebugOptions {
+        strip_prefix: Some("tests/"),
+        ..DebugOptions::default()
+    };
+    let stripped = format!("{:?}", WithOptions(e.frame(), options));
+    assert!(stripped.starts_with("E6, at generate/mod.rs:"));
+}
+
+#[test]
+fn dedup_children_sums_multiplicities() {
+    let children = || (0..3).map(|_| Exn::new(Error("E1")));
+    let e = Exn::raise_all(children(), Error("E2")).dedup_children();
+    let e = Exn::raise_all(children(), Error("E2"))
+        .with_siblings(e.split().1)
+        .dedup_children();
+    assert_eq!(e.frame().children().len(), 1);
+    assert_eq!(e.frame().children()[0].multiplicity(), 6);
+}
+
+#[test]
+fn debug_max_children_with_help() {
+    use std::fmt::{self, Debug, Formatter};
+
+    use exn::{DebugOptions, Frame};
+
+    struct Limited<'a>(&'a Frame);
+
+    impl Debug for Limited<'_> {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            let options = DebugOptions {
+                locations: false,
+                max_children: Some(1),
+                ..DebugOptions::default()
+            };
+            self.0.debug_with(f, &options)
+        }
+    }
+
+    let children = ["shown", "hidden"].map(|help| Exn::new(Error("child")).with_help(help));
+    let e = Exn::raise_all(children, Error("parent")).with_help("parent");
+    insta::assert_snapshot!(format!("{:?}", Limited(e.frame())), @r"
+    parent
+    ├─ child
+    └─ ... and 1 more
+    help: parent
+    help: shown
+    ");
+    assert_eq!(format!("{e:?}").matches("help: ").count(), 3);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn catch_discards_stale_location() {
+    exn::catch(|| {
+        let _ = std::panic::catch_unwind(|| panic!("inner"));
+    })
+    .unwrap();
+
+    let exn = exn::catch(|| std::panic::resume_unwind(Box::new("later"))).unwrap_err();
+    assert_eq!(exn.to_string(), "later");
+    assert_eq!(exn.location(), None);
+}
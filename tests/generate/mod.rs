@@ -14,16 +14,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use exn::{ErrorExt, Exn, ExnAny, Repr};
+use exn::{ErrorExt, Exn};
 
-#[derive(Debug, thiserror::Error)]
+#[derive(Clone, Debug, thiserror::Error)]
 #[error("{0}")]
 pub struct Error(pub &'static str);
 
-pub fn tree<T>() -> Result<(), ExnAny<T>>
+pub fn tree<T>() -> Result<(), T>
 where
-    T: Repr,
-    ExnAny<T>: From<Exn<Error>>,
+    T: From<Exn<Error>>,
 {
     let e1 = Error("E1").raise();
     let e3 = e1.raise(Error("E3"));
@@ -45,10 +44,9 @@ where
     Err(Exn::raise_all([e5, e4, e8], Error("E6")).into())
 }
 
-pub fn list<T>() -> Result<(), ExnAny<T>>
+pub fn list<T>() -> Result<(), T>
 where
-    T: Repr,
-    ExnAny<T>: From<Exn<Error>>,
+    T: From<Exn<Error>>,
 {
     let e1 = Error("E1").raise();
     let e2 = e1.raise(Error("E2"));
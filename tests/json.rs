@@ -0,0 +1,119 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "json")]
+
+mod generate;
+
+use exn::{ExnAny, repr::Json};
+
+#[test]
+fn list_repr_json() {
+    let e = generate::list::<ExnAny<Json>>().unwrap_err();
+    insta::assert_compact_debug_snapshot!(e);
+}
+
+#[test]
+fn tree_repr_json() {
+    let e = generate::tree::<ExnAny<Json>>().unwrap_err();
+
+    let value = e.to_json_value();
+    assert_eq!(value["message"], "E6");
+    assert_eq!(value["file"], "tests/generate/mod.rs");
+    assert!(value["line"].is_u64());
+    assert!(value["column"].is_u64());
+    assert_eq!(value["children"].as_array().unwrap().len(), 3);
+    assert_eq!(value["children"][0]["message"], "E5");
+    assert_eq!(value["children"][0]["children"][1]["message"], "E10");
+    assert_eq!(value["children"][2]["children"][0]["message"], "E7");
+
+    insta::assert_compact_debug_snapshot!(e);
+}
+
+#[test]
+fn frame_data() {
+    use exn::{Frame, FrameData};
+
+    let e = generate::list::<ExnAny<Json>>().unwrap_err();
+    let data: FrameData = serde_json::from_value(e.to_json_value()).unwrap();
+    assert_eq!(data.message, "E5");
+    assert_eq!(data.file, "tests/generate/mod.rs");
+    assert_eq!(data.children.len(), 1);
+
+    let frame = Frame::from(data);
+    assert_eq!(frame.location().file(), "tests/json.rs");
+    assert_eq!(serde_json::to_value(&frame).unwrap(), e.to_json_value());
+    insta::assert_snapshot!(frame.to_tree_string(), @r"
+    E5, at tests/generate/mod.rs:55:12
+    ├─ E4, at tests/generate/mod.rs:54:17
+    ├─ E3, at tests/generate/mod.rs:53:17
+    ├─ E2, at tests/generate/mod.rs:52:17
+    └─ E1, at tests/generate/mod.rs:51:26
+    ");
+}
+
+#[test]
+fn frame_data_metadata() {
+    use std::io;
+
+    use exn::{ErrorExt, Exn, Frame, FrameData, Level};
+
+    let children = (0..2).map(|_| io::Error::other("child").raise().with_code(2));
+    let exn: ExnAny<Json> = Exn::raise_all(children, io::Error::other("parent"))
+        .dedup_children()
+        .with_level(Level::Warn)
+        .with_help("check the path")
+        .with_span("path = a.txt", 7..12, "here")
+        .attach("path", "a.txt")
+        .into();
+
+    let data: FrameData = serde_json::from_value(exn.to_json_value()).unwrap();
+    assert_eq!(data.level, Some(Level::Warn));
+    assert_eq!(data.help.as_deref(), Some("check the path"));
+    assert_eq!(
+        data.span.as_ref().map(|span| span.range.clone()),
+        Some(7..12)
+    );
+    assert_eq!(data.attachments, [("path".into(), "a.txt".into())]);
+    assert_eq!(data.children[0].code, Some(2));
+    assert_eq!(data.children[0].multiplicity, 2);
+
+    let frame = Frame::from(data);
+    assert_eq!(frame.level(), Some(Level::Warn));
+    assert_eq!(frame.help(), Some("check the path"));
+    assert_eq!(frame.span().map(|span| span.label.as_str()), Some("here"));
+    assert_eq!(frame.attachments(), [("path".into(), "a.txt".into())]);
+    assert_eq!(frame.children()[0].code(), Some(2));
+    assert_eq!(frame.children()[0].multiplicity(), 2);
+    assert_eq!(serde_json::to_value(&frame).unwrap(), exn.to_json_value());
+}
+
+#[test]
+fn attachments_with_duplicate_keys() {
+    use std::io;
+
+    use exn::ErrorExt;
+
+    let exn: ExnAny<Json> = io::Error::other("failed")
+        .raise()
+        .attach("path", "a.txt")
+        .attach("path", "b.txt")
+        .into();
+
+    let value = exn.to_json_value();
+    assert_eq!(
+        value["attachments"],
+        serde_json::json!([["path", "a.txt"], ["path", "b.txt"]])
+    );
+}
@@ -0,0 +1,61 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "miette")]
+
+mod generate;
+
+use exn::{ExnAny, repr::Miette};
+use miette::{NarratableReportHandler, Report};
+
+fn render(report: &Report) -> String {
+    let mut rendered = String::new();
+    NarratableReportHandler::new()
+        .render_report(&mut rendered, report.as_ref())
+        .unwrap();
+    rendered
+}
+
+#[test]
+fn tree_repr_miette() {
+    fn report() -> miette::Result<()> {
+        generate::tree::<ExnAny<Miette>>()?;
+        Ok(())
+    }
+
+    let rendered = render(&report().unwrap_err());
+    for i in 1..=12 {
+        assert_eq!(rendered.matches(&format!("E{i}\n")).count(), 1, "E{i}");
+    }
+    assert_eq!(rendered.matches("at tests/generate/mod.rs:").count(), 12);
+}
+
+#[test]
+fn list_repr_miette() {
+    let report = Report::new(generate::list::<ExnAny<Miette>>().unwrap_err());
+    insta::assert_snapshot!(render(&report));
+}
+
+#[test]
+fn span_repr_miette() {
+    fn report() -> miette::Result<()> {
+        let e = exn::Exn::new(generate::Error("E1")).with_span("let x = ;", 8..9, "here");
+        Err(ExnAny::<Miette>::from(e))?;
+        Ok(())
+    }
+
+    let rendered = render(&report().unwrap_err());
+    assert!(rendered.contains("here"), "{rendered}");
+    assert!(rendered.contains("let x = ;"), "{rendered}");
+}
@@ -0,0 +1,53 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Exercises the public API from a `no_std` crate to ensure that it does not depend on `std`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec::Vec};
+use core::fmt;
+
+use exn::{ErrorExt, Exn, Message, OptionExt, Result, ResultExt, bail, ensure};
+
+fn fail() -> Result<(), fmt::Error> {
+    bail!(fmt::Error)
+}
+
+fn fail_format(value: u32) -> Result<(), Message> {
+    ensure!(value == 0, "expected zero, got {value}");
+    Ok(())
+}
+
+#[test]
+fn no_std() {
+    let e: Exn<Message> = fail().or_raise(|| Message::new("outer")).unwrap_err();
+    assert_eq!(e.to_string(), "outer");
+
+    let e = fail_format(1).unwrap_err();
+    assert_eq!(e.to_string(), "expected zero, got 1");
+
+    let e: Exn<fmt::Error> = None::<()>.ok_or_raise(|| fmt::Error).unwrap_err();
+    let e = e.raise(Message::new("raised"));
+    let messages: Vec<_> = e.frame().iter().map(ToString::to_string).collect();
+    assert_eq!(
+        messages,
+        ["raised", "an error occurred when formatting an argument"]
+    );
+
+    let e: Exn<fmt::Error> = fmt::Error.raise();
+    assert_eq!(e.count(), 1);
+}
@@ -0,0 +1,39 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "rayon")]
+
+use exn::ParallelIteratorExt;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+#[test]
+fn collect_all_ok() {
+    let values = (0..100)
+        .into_par_iter()
+        .map(Ok::<_, i32>)
+        .collect_all::<Vec<_>, Vec<_>>()
+        .unwrap();
+    assert_eq!(values, (0..100).collect::<Vec<_>>());
+}
+
+#[test]
+fn collect_all_err() {
+    let mut errors = (0..100)
+        .into_par_iter()
+        .map(|i| if i % 3 == 0 { Err(i) } else { Ok(i) })
+        .collect_all::<Vec<_>, Vec<_>>()
+        .unwrap_err();
+    errors.sort_unstable();
+    assert_eq!(errors, (0..100).step_by(3).collect::<Vec<_>>());
+}
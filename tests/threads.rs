@@ -0,0 +1,79 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "threads")]
+
+use std::{io::Error, thread};
+
+use exn::{Exn, Frame};
+
+#[test]
+fn thread_names() {
+    let children: Vec<_> = ["worker-1", "worker-2"]
+        .into_iter()
+        .map(|name| {
+            thread::Builder::new()
+                .name(name.to_owned())
+                .spawn(|| Exn::new(Error::other("E1")))
+                .unwrap()
+        })
+        .map(|handle| handle.join().unwrap())
+        .collect();
+    let e = Exn::raise_all(children, Error::other("E2"));
+
+    assert_eq!(e.frame().thread_name(), thread::current().name());
+    assert_eq!(e.frame().thread_id(), Some(thread::current().id()));
+    let names: Vec<_> = e
+        .frame()
+        .children()
+        .iter()
+        .map(Frame::thread_name)
+        .collect();
+    assert_eq!(names, [Some("worker-1"), Some("worker-2")]);
+}
+
+#[test]
+fn debug() {
+    use std::fmt::{self, Debug, Formatter};
+
+    use exn::DebugOptions;
+
+    struct Threads<'a>(&'a Frame);
+
+    impl Debug for Threads<'_> {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            let options = DebugOptions {
+                locations: false,
+                threads: true,
+                ..DebugOptions::default()
+            };
+            self.0.debug_with(f, &options)
+        }
+    }
+
+    let child = thread::Builder::new()
+        .name("worker".to_owned())
+        .spawn(|| Exn::new(Error::other("E1")))
+        .unwrap()
+        .join()
+        .unwrap();
+    let e = thread::spawn(|| child.raise(Error::other("E2")))
+        .join()
+        .unwrap();
+    let id = e.frame().thread_id().unwrap();
+    assert_eq!(
+        format!("{:?}", Threads(e.frame())),
+        format!("E2 (thread={id:?})\n└─ E1 (thread=worker)"),
+    );
+}
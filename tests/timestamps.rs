@@ -0,0 +1,71 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "timestamps")]
+
+mod generate;
+
+use exn::{Exn, Frame};
+
+#[test]
+fn monotonic() {
+    fn check(frame: &Frame) {
+        let timestamp = frame.timestamp().unwrap();
+        for child in frame.children() {
+            assert!(child.timestamp().unwrap() <= timestamp);
+            check(child);
+        }
+    }
+
+    let e = generate::tree::<Exn<generate::Error>>().unwrap_err();
+    check(e.frame());
+}
+
+#[test]
+fn pruned() {
+    let e = generate::list::<Exn<generate::Error>>().unwrap_err();
+    let e = e.prune_depth(1);
+    let marker = &e.frame().children()[0].children()[0];
+    assert_eq!(marker.timestamp(), None);
+}
+
+#[test]
+fn debug() {
+    use std::fmt::{self, Debug, Formatter};
+
+    use exn::DebugOptions;
+
+    struct Timestamped<'a>(&'a Frame);
+
+    impl Debug for Timestamped<'_> {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            let options = DebugOptions {
+                timestamps: true,
+                ..DebugOptions::default()
+            };
+            self.0.debug_with(f, &options)
+        }
+    }
+
+    let e = Exn::new(generate::Error("E1"));
+    let rendered = format!("{:?}", Timestamped(e.frame()));
+    let (_, timestamp) = rendered.split_once(" (t=").unwrap();
+    let (secs, micros) = timestamp.trim_end_matches(')').split_once('.').unwrap();
+    assert!(secs.parse::<u64>().unwrap() > 0);
+    assert_eq!(micros.len(), 6);
+    assert_eq!(
+        format!("{:?}", e.frame()),
+        rendered.split(" (t=").next().unwrap()
+    );
+}
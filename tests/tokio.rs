@@ -0,0 +1,39 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "tokio")]
+
+use exn::TaskError;
+
+#[tokio::test]
+async fn panicked() {
+    let handle = tokio::spawn(async { panic!("oh no") });
+    let e = exn::from_join_error(handle.await.unwrap_err());
+    assert_eq!(e.to_string(), "task panicked: oh no");
+    assert_eq!(e.frame().location().file(), "tests/tokio.rs");
+    let TaskError::Panicked(error) = &*e else {
+        panic!("expected a panic");
+    };
+    assert_eq!(error.message(), Some("oh no"));
+    assert_eq!(error.location(), None);
+}
+
+#[tokio::test]
+async fn cancelled() {
+    let handle = tokio::spawn(std::future::pending::<()>());
+    handle.abort();
+    let e = exn::from_join_error(handle.await.unwrap_err());
+    assert_eq!(e.to_string(), "task was cancelled");
+    assert!(matches!(*e, TaskError::Cancelled));
+}
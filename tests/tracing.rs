@@ -0,0 +1,195 @@
+// Copyright 2026 Andrew Lehmer (github.com/80Ltrumpet)
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "tracing")]
+
+mod generate;
+
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use exn::{Exn, ExnAny, repr::Tracing};
+use tracing::{
+    Event, Level, Metadata, Subscriber,
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+};
+use tracing_core::span::Current;
+
+use self::generate::Error;
+
+#[derive(Debug, Default)]
+struct Captured {
+    message: String,
+    file: String,
+    line: u64,
+    depth: u64,
+}
+
+impl Visit for Captured {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "file" {
+            self.file = value.to_owned();
+        }
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        match field.name() {
+            "line" => self.line = value,
+            "depth" => self.depth = value,
+            _ => {}
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Vec<Captured>>>);
+
+impl Subscriber for Capture {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &Attributes) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event) {
+        assert_eq!(*event.metadata().level(), Level::ERROR);
+        let mut captured = Captured::default();
+        event.record(&mut captured);
+        self.0.lock().unwrap().push(captured);
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn tree_repr_tracing() {
+    let e = generate::tree::<ExnAny<Tracing>>().unwrap_err();
+
+    let capture = Capture::default();
+    tracing::subscriber::with_default(capture.clone(), || e.emit());
+
+    let events = capture.0.lock().unwrap();
+    let summary: Vec<_> = events
+        .iter()
+        .map(|event| (event.message.as_str(), event.depth))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("E6", 0),
+            ("E5", 1),
+            ("E3", 2),
+            ("E1", 3),
+            ("E10", 2),
+            ("E9", 3),
+            ("E12", 2),
+            ("E11", 3),
+            ("E4", 1),
+            ("E2", 2),
+            ("E8", 1),
+            ("E7", 2),
+        ]
+    );
+    assert!(
+        events
+            .iter()
+            .all(|event| event.file == "tests/generate/mod.rs")
+    );
+    assert!(events.iter().all(|event| event.line > 0));
+}
+
+#[test]
+fn list_repr_tracing() {
+    let e = generate::list::<ExnAny<Tracing>>().unwrap_err();
+    insta::assert_compact_debug_snapshot!(e);
+}
+
+/// [`Subscriber`] that tracks the current span
+#[derive(Default)]
+struct Spans {
+    metadata: Mutex<Vec<&'static Metadata<'static>>>,
+    entered: Mutex<Vec<Id>>,
+}
+
+impl Subscriber for Spans {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn new_span(&self, attributes: &Attributes) -> Id {
+        let mut metadata = self.metadata.lock().unwrap();
+        metadata.push(attributes.metadata());
+        Id::from_u64(metadata.len() as u64)
+    }
+
+    fn record(&self, _: &Id, _: &Record) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, _: &Event) {}
+
+    fn enter(&self, id: &Id) {
+        self.entered.lock().unwrap().push(id.clone());
+    }
+
+    fn exit(&self, _: &Id) {
+        self.entered.lock().unwrap().pop();
+    }
+
+    fn current_span(&self) -> Current {
+        match self.entered.lock().unwrap().last() {
+            Some(id) => {
+                let index = usize::try_from(id.into_u64() - 1).unwrap();
+                Current::new(id.clone(), self.metadata.lock().unwrap()[index])
+            }
+            None => Current::none(),
+        }
+    }
+}
+
+#[test]
+fn tracing_span() {
+    assert!(Exn::new(Error("E1")).frame().tracing_span().is_none());
+
+    let e = tracing::subscriber::with_default(Spans::default(), || {
+        assert!(Exn::new(Error("E1")).frame().tracing_span().is_none());
+
+        let span = tracing::info_span!("request");
+        let _guard = span.enter();
+        let e = Exn::new(Error("E1")).raise(Error("E2"));
+        assert!(e.frame().tracing_span().is_none());
+        assert_eq!(e.frame().children()[0].tracing_span(), Some(&span));
+        e
+    });
+
+    let span = e.frame().children()[0].tracing_span().unwrap();
+    assert_eq!(span.metadata().unwrap().name(), "request");
+}